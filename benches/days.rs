@@ -0,0 +1,54 @@
+use advent_of_code::inputs;
+use advent_of_code::registry::{Day, DAYS};
+use advent_of_code::runner::Stage;
+use advent_of_code::solution::Part;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+const STAGES: [Stage; 3] = [Stage::Parse, Stage::Part(Part::One), Stage::Part(Part::Two)];
+
+/// The real input of a day, when there is one locally.
+fn local_input(day: &Day) -> Option<String> {
+    let path = inputs::resolve(day.year, &inputs::file_name(day.day, None)).ok()?;
+    inputs::read_file(&path).ok()
+}
+
+fn bench_days(c: &mut Criterion) {
+    let mut years: Vec<u16> = DAYS.iter().map(|day| day.year).collect();
+    years.dedup();
+
+    for year in years {
+        let mut group = c.benchmark_group(year.to_string());
+
+        for day in DAYS.iter().filter(|day| day.year == year) {
+            let Some(contents) = local_input(day) else {
+                eprintln!("Skipping {} day {}: no local input", day.year, day.day);
+                continue;
+            };
+
+            let variants = [Part::One, Part::Two].into_iter().flat_map(|part| {
+                (day.variants)(part)
+                    .into_iter()
+                    .map(move |name| Stage::Variant(part, name))
+            });
+
+            for stage in STAGES.into_iter().chain(variants) {
+                // Check once up front, a failing stage would abort the whole suite
+                if let Err(e) = (day.measure)(&contents, stage, 1) {
+                    eprintln!("Skipping {} day {} {}: {}", day.year, day.day, stage, e);
+                    continue;
+                }
+
+                group.bench_function(format!("day{}/{}", day.day, stage), |b| {
+                    b.iter_custom(|iterations| {
+                        (day.measure)(&contents, stage, iterations).expect("Stage succeeded before")
+                    })
+                });
+            }
+        }
+
+        group.finish();
+    }
+}
+
+criterion_group!(benches, bench_days);
+criterion_main!(benches);
@@ -1,64 +1,54 @@
 use std::collections::HashMap;
-use std::env;
-use std::fs;
-
-pub fn run() {
-    let args: Vec<String> = env::args().collect();
-    let is_test_mode = args.iter().any(|arg| arg == "--test");
-    const INPUT_BASE: &str = "src/2024/input/";
-
-    let filename = if is_test_mode {
-        format!("{}{}", INPUT_BASE, "day1_test.input")
-    } else {
-        format!("{}{}", INPUT_BASE, "day1.input")
-    };
-
-    println!("📆 Day 1 {}:", if is_test_mode { "(DEBUG)" } else { "" });
-    println!("------------");
-    let contents = fs::read_to_string(&filename)
-        .unwrap_or_else(|_| panic!("Failed to read file: {}", filename));
-    let lines: Vec<&str> = contents.lines().collect();
-
-    task_one(&lines);
-    task_two(&lines);
-    println!();
-}
 
-fn task_one(lines: &[&str]) {
-    let (mut list_one, mut list_two): (Vec<u32>, Vec<u32>) = lines
-        .iter()
-        .filter_map(|line| {
-            let mut parts = line.split_whitespace();
+use crate::error::Result;
+use crate::parsing;
+use crate::solution::Solution;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 1;
+
+    type Input<'a> = Vec<(u32, u32)>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parsing::lines(input)
+            .map(
+                |line| match line.text.split_whitespace().collect::<Vec<_>>()[..] {
+                    [a, b] => Ok((line.number(a)?, line.number(b)?)),
+                    _ => Err(line.error(line.text, "Expected two location ids")),
+                },
+            )
+            .collect()
+    }
+
+    fn part_one(pairs: &Self::Input<'_>) -> Result<u32> {
+        Ok(task_one(pairs))
+    }
+
+    fn part_two(pairs: &Self::Input<'_>) -> Result<u32> {
+        Ok(task_two(pairs))
+    }
+}
 
-            let a = parts.next()?.parse::<u32>().ok()?;
-            let b = parts.next()?.parse::<u32>().ok()?;
-            Some((a, b))
-        })
-        .unzip();
+fn task_one(pairs: &[(u32, u32)]) -> u32 {
+    let (mut list_one, mut list_two): (Vec<u32>, Vec<u32>) = pairs.iter().copied().unzip();
 
     list_one.sort_unstable();
     list_two.sort_unstable();
 
-    let difference: u32 = list_one
+    list_one
         .into_iter()
-        .zip(list_two.into_iter())
+        .zip(list_two)
         .map(|(a, b)| a.abs_diff(b))
-        .sum();
-
-    println!("Task 1 - The list difference is: {}", difference);
+        .sum()
 }
 
-fn task_two(lines: &[&str]) {
-    let (list_one, list_two): (Vec<u32>, Vec<u32>) = lines
-        .iter()
-        .filter_map(|line| {
-            let mut parts = line.split_whitespace();
-
-            let a = parts.next()?.parse::<u32>().ok()?;
-            let b = parts.next()?.parse::<u32>().ok()?;
-            Some((a, b))
-        })
-        .unzip();
+fn task_two(pairs: &[(u32, u32)]) -> u32 {
+    let (list_one, list_two): (Vec<u32>, Vec<u32>) = pairs.iter().copied().unzip();
 
     let mut number_counts = HashMap::new();
 
@@ -70,10 +60,38 @@ fn task_two(lines: &[&str]) {
         }
     }
 
-    let similarity_score: u32 = list_two
+    list_two
         .iter()
-        .filter_map(|entry| Some(entry * number_counts.get(&entry)?))
-        .sum();
+        .filter_map(|entry| Some(entry * number_counts.get(entry)?))
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+3   4
+4   3
+2   5
+1   3
+3   9
+3   3
+";
 
-    println!("Task 2 - Similarity Score: {}", similarity_score);
+    #[test]
+    fn part_one_example() {
+        assert_eq!(Day1::part_one(&Day1::parse(EXAMPLE).unwrap()).unwrap(), 11);
+    }
+
+    #[test]
+    fn part_two_example() {
+        assert_eq!(Day1::part_two(&Day1::parse(EXAMPLE).unwrap()).unwrap(), 31);
+    }
+
+    #[test]
+    fn rejects_malformed_lines() {
+        assert!(Day1::parse("3   4\n4\n").is_err());
+        assert!(Day1::parse("3   x\n").is_err());
+    }
 }
@@ -1,122 +1,83 @@
-use std::env;
-use std::fs;
+use crate::error::{AocError, Result};
+use crate::solution::Solution;
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 2;
+
+    type Input<'a> = Vec<Vec<u32>>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                line.split_ascii_whitespace()
+                    .map(|number| {
+                        number
+                            .parse::<u32>()
+                            .map_err(|e| AocError::parse_at(i, line, number, e.to_string()))
+                    })
+                    .collect()
+            })
+            .collect()
+    }
 
-pub fn run() {
-    let args: Vec<String> = env::args().collect();
-    let is_test_mode = args.iter().any(|arg| arg == "--test");
-    const INPUT_BASE: &str = "src/2024/input/";
+    fn part_one(reports: &Self::Input<'_>) -> Result<u32> {
+        Ok(task_one(reports))
+    }
 
-    let filename = if is_test_mode {
-        format!("{}{}", INPUT_BASE, "day2_test.input")
-    } else {
-        format!("{}{}", INPUT_BASE, "day2.input")
-    };
-
-    println!("📆 Day 2 {}:", if is_test_mode { "(DEBUG)" } else { "" });
-    println!("------------");
-    let contents = fs::read_to_string(&filename)
-        .unwrap_or_else(|_| panic!("Failed to read file: {}", filename));
-    let lines: Vec<&str> = contents.lines().collect();
-
-    task_one(&lines);
-    task_two(&lines);
-    println!();
+    fn part_two(reports: &Self::Input<'_>) -> Result<u32> {
+        Ok(task_two(reports))
+    }
 }
 
-fn task_one(lines: &[&str]) {
-    let numbers_lists: Vec<Vec<u32>> = lines
-        .iter()
-        .map(|line| {
-            line.split_ascii_whitespace()
-                .filter_map(|number| Some(number.parse::<u32>().unwrap()))
-                .collect()
-        })
-        .collect();
-
+fn task_one(numbers_lists: &[Vec<u32>]) -> u32 {
     let mut safe_reports_count = 0;
 
     for list in numbers_lists {
-        if list.len() <= 1 {
+        if is_safe_report(list) {
             safe_reports_count += 1;
-        } else {
-            let ascending = list[0] < list[1];
-            let mut is_safe = true;
-
-            for i in 0..list.len() - 1 {
-                if !compare_ascending_decending(list[i], list[i + 1], ascending)
-                    || list[i].abs_diff(list[i + 1]) > 3
-                    || !list[i].abs_diff(list[i + 1]) == 0
-                {
-                    is_safe = false;
-                    break;
-                }
-            }
-
-            if is_safe {
-                safe_reports_count += 1;
-            }
         }
     }
 
-    println!("Task 1 - Safe reports count: {}", safe_reports_count);
+    safe_reports_count
 }
 
-fn task_two(lines: &[&str]) {
-    let numbers_lists: Vec<Vec<u32>> = lines
-        .iter()
-        .map(|line| {
-            line.split_ascii_whitespace()
-                .filter_map(|number| Some(number.parse::<u32>().unwrap()))
-                .collect()
-        })
-        .collect();
-
+fn task_two(numbers_lists: &[Vec<u32>]) -> u32 {
     let mut safe_reports_count = 0;
 
     for list in numbers_lists {
-        if list.len() <= 1 {
+        // The problem dampener tolerates a single bad level
+        let is_safe = is_safe_report(list)
+            || (0..list.len()).any(|index| {
+                let mut dampened_list = list.clone();
+                dampened_list.remove(index);
+                is_safe_report(&dampened_list)
+            });
+
+        if is_safe {
             safe_reports_count += 1;
-        } else {
-            let ascending = list[0] < list[1];
-            let mut is_safe = true;
-
-            for i in 0..list.len() - 1 {
-                if i + 1 < list.len() {
-                    if !compare_ascending_decending(list[i], list[i + 1], ascending)
-                        || list[i].abs_diff(list[i + 1]) > 3
-                        || !list[i].abs_diff(list[i + 1]) == 0
-                    {
-                        is_safe = check_for_safe_list_ignoring_index(ascending, &list, i)
-                            || check_for_safe_list_ignoring_index(ascending, &list, i + 1);
-                    }
-                }
-            }
-
-            if is_safe {
-                safe_reports_count += 1;
-            }
         }
     }
 
-    println!("Task 2 - Safe reports count: {}", safe_reports_count);
+    safe_reports_count
 }
 
-fn check_for_safe_list_ignoring_index(ascending: bool, list: &[u32], index: usize) -> bool {
-    for i in 0..list.len() - 1 {
-        if i + 1 < list.len() {
-            if i == index {
-                continue;
-            }
-            if !compare_ascending_decending(list[i], list[i + 1], ascending)
-                || list[i].abs_diff(list[i + 1]) > 3
-                || !list[i].abs_diff(list[i + 1]) == 0
-            {
-                return false;
-            }
-        }
+fn is_safe_report(list: &[u32]) -> bool {
+    if list.len() <= 1 {
+        return true;
     }
 
-    return true;
+    let ascending = list[0] < list[1];
+
+    list.windows(2).all(|pair| {
+        compare_ascending_decending(pair[0], pair[1], ascending) && pair[0].abs_diff(pair[1]) <= 3
+    })
 }
 
 fn compare_ascending_decending(num1: u32, num2: u32, is_ascending: bool) -> bool {
@@ -126,3 +87,27 @@ fn compare_ascending_decending(num1: u32, num2: u32, is_ascending: bool) -> bool
         num2 < num1
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+7 6 4 2 1
+1 2 7 8 9
+9 7 6 2 1
+1 3 2 4 5
+8 6 4 4 1
+1 3 6 7 9
+";
+
+    #[test]
+    fn part_one_example() {
+        assert_eq!(Day2::part_one(&Day2::parse(EXAMPLE).unwrap()).unwrap(), 2);
+    }
+
+    #[test]
+    fn part_two_example() {
+        assert_eq!(Day2::part_two(&Day2::parse(EXAMPLE).unwrap()).unwrap(), 4);
+    }
+}
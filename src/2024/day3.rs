@@ -1,34 +1,37 @@
 use regex::Regex;
-use std::env;
-use std::fs;
-
-pub fn run() {
-    let args: Vec<String> = env::args().collect();
-    let is_test_mode = args.iter().any(|arg| arg == "--test");
-    const INPUT_BASE: &str = "src/2024/input/";
-
-    let filename = if is_test_mode {
-        format!("{}{}", INPUT_BASE, "day3_test.input")
-    } else {
-        format!("{}{}", INPUT_BASE, "day3.input")
-    };
-
-    println!("📆 Day 3 {}:", if is_test_mode { "(DEBUG)" } else { "" });
-    println!("------------");
-    let contents = fs::read_to_string(&filename)
-        .unwrap_or_else(|_| panic!("Failed to read file: {}", filename));
-
-    task_one(&contents);
-    task_two(&contents);
-    println!();
+
+use crate::error::Result;
+use crate::solution::Solution;
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 3;
+
+    type Input<'a> = &'a str;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<u32> {
+        Ok(task_one(input))
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<u32> {
+        Ok(task_two(input))
+    }
 }
 
-fn task_one(input: &str) {
+fn task_one(input: &str) -> u32 {
     let mul_regex = Regex::new(r"mul\((\d{1,3}),(\d{1,3})\)").unwrap();
 
     let mut mul_pairs = vec![];
 
-    for (_, [mul_1, mul_2]) in mul_regex.captures_iter(&input).map(|mul| mul.extract()) {
+    for (_, [mul_1, mul_2]) in mul_regex.captures_iter(input).map(|mul| mul.extract()) {
         mul_pairs.push((
             mul_1.parse::<u32>().expect("Could not unwrap first number"),
             mul_2
@@ -37,15 +40,10 @@ fn task_one(input: &str) {
         ));
     }
 
-    let mut result = 0;
-    for (x, y) in mul_pairs {
-        result += x * y;
-    }
-
-    println!("Task 1 - The result is: {}", result);
+    mul_pairs.iter().map(|(x, y)| x * y).sum()
 }
 
-fn task_two(input: &str) {
+fn task_two(input: &str) -> u32 {
     let parts: Vec<&str> = input.split("don't()").collect();
 
     let mut valid_strings: Vec<&str> = vec![parts[0]];
@@ -70,10 +68,22 @@ fn task_two(input: &str) {
         ));
     }
 
-    let mut result = 0;
-    for (x, y) in mul_pairs {
-        result += x * y;
+    mul_pairs.iter().map(|(x, y)| x * y).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_one_example() {
+        let example = "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))";
+        assert_eq!(Day3::part_one(&Day3::parse(example).unwrap()).unwrap(), 161);
     }
 
-    println!("Task 1 - The result is: {}", result);
+    #[test]
+    fn part_two_example() {
+        let example = "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+        assert_eq!(Day3::part_two(&Day3::parse(example).unwrap()).unwrap(), 48);
+    }
 }
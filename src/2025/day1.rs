@@ -1,51 +1,61 @@
-use std::env;
-use std::fs;
+use crate::error::Result;
+use crate::parsing;
+use crate::solution::Solution;
 
-pub fn run() {
-    let args: Vec<String> = env::args().collect();
-    let is_test_mode = args.iter().any(|arg| arg == "--test");
-    const INPUT_BASE: &str = "src/2025/input/";
+pub struct Day1;
 
-    let filename = if is_test_mode {
-        format!("{}{}", INPUT_BASE, "day1_test.input")
-    } else {
-        format!("{}{}", INPUT_BASE, "day1.input")
-    };
-
-    println!("📆 Day 1 {}:", if is_test_mode { "(DEBUG)" } else { "" });
-    println!("------------");
-    let contents = fs::read_to_string(&filename)
-        .unwrap_or_else(|_| panic!("Failed to read file: {}", filename));
-    let commands: Vec<&str> = contents.lines().collect();
-
-    task_one(&commands);
-    task_two(&commands);
-    println!();
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Left,
+    Right,
 }
 
-fn task_one(commands: &Vec<&str>) {
+impl Solution for Day1 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 1;
+
+    type Input<'a> = Vec<(Direction, i32)>;
+    type PartOne = u32;
+    type PartTwo = i32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parsing::lines(input)
+            .map(|line| {
+                let split = line.text.chars().next().map_or(0, char::len_utf8);
+                let (direction, number) = line.text.split_at(split);
+                let direction = match direction {
+                    "L" => Direction::Left,
+                    "R" => Direction::Right,
+                    _ => return Err(line.error(direction, "Expected a direction 'L' or 'R'")),
+                };
+                Ok((direction, line.number(number)?))
+            })
+            .collect()
+    }
+
+    fn part_one(commands: &Self::Input<'_>) -> Result<u32> {
+        Ok(task_one(commands))
+    }
+
+    fn part_two(commands: &Self::Input<'_>) -> Result<i32> {
+        Ok(task_two(commands))
+    }
+}
+
+fn task_one(commands: &[(Direction, i32)]) -> u32 {
     const COUNT_GOAL: u8 = 0;
     let mut position: i32 = 50;
     let mut password = 0;
 
-    for command in commands {
-        let (direction, number_str) = command.split_at(1);
-        match number_str.parse::<i32>() {
-            Ok(number) => match direction {
-                "L" => {
-                    position -= number;
-                    position %= 100;
-                }
-                "R" => {
-                    position += number;
-                    position %= 100;
-                }
-                _ => {
-                    eprintln!("Unexpected direction.");
-                }
-            },
-            Err(e) => {
-                eprintln!("Failed to parse number '{}': {}", number_str, e);
+    for &(direction, number) in commands {
+        match direction {
+            Direction::Left => {
+                position -= number;
+                position %= 100;
+            }
+            Direction::Right => {
+                position += number;
+                position %= 100;
             }
         }
         if position == COUNT_GOAL as i32 {
@@ -53,43 +63,33 @@ fn task_one(commands: &Vec<&str>) {
         }
     }
 
-    println!("1: The password is: {}", password);
+    password
 }
 
-fn task_two(commands: &Vec<&str>) {
+fn task_two(commands: &[(Direction, i32)]) -> i32 {
     let mut position: i32 = 50;
     let mut password = 0;
 
-    for command in commands {
-        let (direction, number_str) = command.split_at(1);
-        match number_str.parse::<i32>() {
-            Ok(rotation) => match direction {
-                "L" => {
-                    let absolute_position =
-                        if position > 0 { 100 - position } else { 0 } + rotation;
-
-                    password += absolute_position.abs() / 100;
-
-                    position = rotate_left(position, rotation);
-                }
-                "R" => {
-                    let absolute_position = position + rotation;
-
-                    password += absolute_position.abs() / 100;
-
-                    position = rotate_right(position, rotation);
-                }
-                _ => {
-                    eprintln!("Unexpected direction.");
-                }
-            },
-            Err(e) => {
-                eprintln!("Failed to parse number '{}': {}", number_str, e);
+    for &(direction, rotation) in commands {
+        match direction {
+            Direction::Left => {
+                let absolute_position = if position > 0 { 100 - position } else { 0 } + rotation;
+
+                password += absolute_position.abs() / 100;
+
+                position = rotate_left(position, rotation);
+            }
+            Direction::Right => {
+                let absolute_position = position + rotation;
+
+                password += absolute_position.abs() / 100;
+
+                position = rotate_right(position, rotation);
             }
         }
     }
 
-    println!("2: The password is: {}", password);
+    password
 }
 
 fn rotate_left(position: i32, rotation: i32) -> i32 {
@@ -113,3 +113,38 @@ fn rotate_right(position: i32, rotation: i32) -> i32 {
         rotated_value
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+L68
+L30
+R48
+L5
+R60
+L55
+L1
+L99
+R14
+L82
+";
+
+    #[test]
+    fn part_one_example() {
+        assert_eq!(Day1::part_one(&Day1::parse(EXAMPLE).unwrap()).unwrap(), 3);
+    }
+
+    #[test]
+    fn part_two_example() {
+        assert_eq!(Day1::part_two(&Day1::parse(EXAMPLE).unwrap()).unwrap(), 6);
+    }
+
+    #[test]
+    fn rejects_malformed_lines() {
+        assert!(Day1::parse("R10\n\nL5\n").is_err());
+        assert!(Day1::parse("X10\n").is_err());
+        assert!(Day1::parse("Lten\n").is_err());
+    }
+}
@@ -1,94 +1,89 @@
 use std::collections::{HashSet, VecDeque};
-use std::env;
-use std::fs;
 use z3::ast::Int;
 use z3::{Optimize, SatResult};
 
+use crate::error::{AocError, Result};
+use crate::parsing::{self, Line};
+use crate::solution::Solution;
+
 const ACTIVE_CHAR: char = '#';
 const INACTIVE_CHAR: char = '.';
 const DELIMITTER: char = ',';
 
-const INPUT_BASE: &str = "src/2025/input/";
-const CURR_DAY: u8 = 10;
-
-struct Machine {
+pub struct Machine {
     goal_state: Vec<usize>,
     buttons: Vec<Vec<usize>>,
     joltage_requirements: Vec<u32>,
 }
 
-pub fn run() {
-    let args: Vec<String> = env::args().collect();
-    let is_test_mode = args.iter().any(|arg| arg == "--test");
-
-    let filename = if is_test_mode {
-        format!("{}{}{}{}", INPUT_BASE, "day", CURR_DAY, "_test.input")
-    } else {
-        format!("{}{}{}{}", INPUT_BASE, "day", CURR_DAY, ".input")
-    };
-
-    println!(
-        "📆 Day {}{}:",
-        CURR_DAY,
-        if is_test_mode { " (DEBUG)" } else { "" }
-    );
-    println!("------------");
-    let file_content = fs::read_to_string(&filename)
-        .unwrap_or_else(|_| panic!("Failed to read file: {}", filename));
-
-    let machines: Vec<Machine> = file_content
-        .lines()
-        .map(|line| {
-            let tokens: Vec<&str> = line.split_ascii_whitespace().collect();
-            assert!(tokens.len() >= 3);
-
-            let goal_state: Vec<usize> = tokens[0][1..tokens[0].len() - 1]
-                .chars()
-                .enumerate()
-                .filter_map(|(i, state)| match state {
-                    ACTIVE_CHAR => Some(i),
-                    INACTIVE_CHAR => None,
-                    _ => panic!("Unexpected char read when parsing state: {}", state),
-                })
-                .collect();
-
-            let raw_cost = tokens[tokens.len() - 1];
-            let joltage_requirements: Vec<u32> = raw_cost[1..raw_cost.len() - 1]
-                .split(DELIMITTER)
-                .map(|num| num.parse::<u32>().expect("Could not parse joltage cost"))
-                .collect();
-
-            let buttons: Vec<Vec<usize>> = tokens[1..tokens.len() - 1]
-                .iter()
-                .map(|s| {
-                    assert!(s.len() >= 3, "Token too short: {}", s);
-
-                    s[1..s.len() - 1]
-                        .split(',')
-                        .map(|num| {
-                            num.trim()
-                                .parse::<usize>()
-                                .expect("Could not parse button number")
-                        })
-                        .collect()
+pub struct Day10;
+
+impl Solution for Day10 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 10;
+
+    type Input<'a> = Vec<Machine>;
+    type PartOne = u32;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parsing::lines(input)
+            .map(|line| {
+                let tokens: Vec<&str> = line.text.split_ascii_whitespace().collect();
+                let [goal_token, button_tokens @ .., joltage_token] = tokens.as_slice() else {
+                    return Err(line.error(
+                        line.text,
+                        "Expected a goal state, buttons and joltage requirements",
+                    ));
+                };
+                if button_tokens.is_empty() {
+                    return Err(line.error(line.text, "Expected at least one button"));
+                }
+
+                let goal_state = parse_goal_state(line, line.bracketed(goal_token, '[', ']')?)?;
+
+                let joltage_requirements =
+                    line.numbers(line.bracketed(joltage_token, '{', '}')?, DELIMITTER)?;
+
+                let buttons = button_tokens
+                    .iter()
+                    .map(|button| line.numbers(line.bracketed(button, '(', ')')?, DELIMITTER))
+                    .collect::<Result<Vec<Vec<usize>>>>()?;
+
+                Ok(Machine {
+                    goal_state,
+                    buttons,
+                    joltage_requirements,
                 })
-                .collect();
+            })
+            .collect()
+    }
 
-            Machine {
-                goal_state,
-                buttons,
-                joltage_requirements,
-            }
-        })
-        .collect();
+    fn part_one(machines: &Self::Input<'_>) -> Result<u32> {
+        Ok(task_one(machines))
+    }
 
-    task_one(&machines);
-    task_two(&machines);
+    fn part_two(machines: &Self::Input<'_>) -> Result<u64> {
+        task_two(machines)
+    }
+}
 
-    println!();
+// Indices of the active lights of a `.##.` goal state
+fn parse_goal_state(line: Line<'_>, goal_token: &str) -> Result<Vec<usize>> {
+    goal_token
+        .char_indices()
+        .filter_map(|(j, state)| match state {
+            ACTIVE_CHAR => Some(Ok(j)),
+            INACTIVE_CHAR => None,
+            _ => Some(Err(line.error(
+                &goal_token[j..],
+                format!("Unexpected char read when parsing state: {}", state),
+            ))),
+        })
+        .collect()
 }
 
-fn task_one(machines: &[Machine]) {
+fn task_one(machines: &[Machine]) -> u32 {
     let mut presses_needed = 0;
 
     for Machine {
@@ -152,23 +147,26 @@ fn task_one(machines: &[Machine]) {
         }
     }
 
-    println!("Task 1 - Total presses needed: {}", presses_needed);
+    presses_needed
 }
 
-fn task_two(machines: &[Machine]) {
+fn task_two(machines: &[Machine]) -> Result<u64> {
     let mut total_presses: u64 = 0;
 
-    for machine in machines {
+    for (i, machine) in machines.iter().enumerate() {
         if machine.joltage_requirements.iter().sum::<u32>() == 0 {
             continue;
         }
 
-        if let Some(presses) = solve_machine_z3(machine) {
-            total_presses += presses;
-        }
+        total_presses += solve_machine_z3(machine).ok_or_else(|| {
+            AocError::solver(format!(
+                "Machine {} cannot reach its joltage requirements",
+                i + 1
+            ))
+        })?;
     }
 
-    println!("Task 2 - Total presses needed: {}", total_presses);
+    Ok(total_presses)
 }
 
 fn solve_machine_z3(machine: &Machine) -> Option<u64> {
@@ -208,10 +206,33 @@ fn solve_machine_z3(machine: &Machine) -> Option<u64> {
     opt.minimize(&total_cost);
 
     if opt.check(&[]) == SatResult::Sat {
-        let model = opt.get_model().unwrap();
-        let result = model.eval(&total_cost, true).unwrap();
-        return Some(result.as_u64().unwrap());
+        let model = opt.get_model()?;
+        return model.eval(&total_cost, true)?.as_u64();
     }
 
     None
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}
+[...#.] (0,2,3,4) (2,3) (0,4) (0,1,2) (1,2,3,4) {7,5,12,7,2}
+[.###.#] (0,1,2,3,4) (0,3,4) (0,1,2,4,5) (1,2) {10,11,11,5,10,5}
+";
+
+    #[test]
+    fn part_one_example() {
+        assert_eq!(Day10::part_one(&Day10::parse(EXAMPLE).unwrap()).unwrap(), 7);
+    }
+
+    #[test]
+    fn part_two_example() {
+        assert_eq!(
+            Day10::part_two(&Day10::parse(EXAMPLE).unwrap()).unwrap(),
+            33
+        );
+    }
+}
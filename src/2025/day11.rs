@@ -2,8 +2,10 @@ use petgraph::algo::all_simple_paths;
 use petgraph::graph::{DiGraph, NodeIndex};
 use std::collections::hash_map::RandomState;
 use std::collections::HashMap;
-use std::env;
-use std::fs;
+
+use crate::error::Result;
+use crate::parsing;
+use crate::solution::Solution;
 
 const YOU_NODE: &str = "you";
 const END_NODE: &str = "out";
@@ -17,94 +19,81 @@ const SVR_NODE_IDX: u32 = 2;
 const FFT_NODE_IDX: u32 = 3;
 const DAC_NODE_IDX: u32 = 4;
 
-const INPUT_BASE: &str = "src/2025/input/";
-const CURR_DAY: u8 = 11;
-
-pub fn run() {
-    let args: Vec<String> = env::args().collect();
-    let is_test_mode = args.iter().any(|arg| arg == "--test");
-
-    let filename = if is_test_mode {
-        format!("{}{}{}{}", INPUT_BASE, "day", CURR_DAY, "_test.input")
-    } else {
-        format!("{}{}{}{}", INPUT_BASE, "day", CURR_DAY, ".input")
-    };
-
-    println!(
-        "📆 Day {}{}:",
-        CURR_DAY,
-        if is_test_mode { " (DEBUG)" } else { "" }
-    );
-    println!("------------");
-
-    let file_content = fs::read_to_string(&filename)
-        .unwrap_or_else(|_| panic!("Failed to read file: {}", filename));
-
-    let edges_raw: Vec<(&str, &str)> = file_content
-        .lines()
-        .flat_map(|line| {
-            let tokens: Vec<&str> = line.split_ascii_whitespace().collect();
-            assert!(tokens.len() >= 2);
-
-            let start_node = tokens[0].trim_end_matches(':');
-
-            tokens[1..]
-                .iter()
-                .map(move |&target| (start_node, target))
-                .collect::<Vec<_>>()
-                .into_iter()
-        })
-        .collect();
-
-    let mut graph = DiGraph::<&str, ()>::new();
-    let mut node_map: HashMap<&str, NodeIndex> = HashMap::new();
-
-    let s_idx = graph.add_node(YOU_NODE);
-    let e_idx = graph.add_node(END_NODE);
-    let srv_idx = graph.add_node(SVR_NODE);
-    let fft_idx = graph.add_node(FFT_NODE);
-    let dac_idx = graph.add_node(DAC_NODE);
-
-    assert_eq!(s_idx.index() as u32, YOU_NODE_IDX);
-    assert_eq!(e_idx.index() as u32, END_IDX);
-    assert_eq!(srv_idx.index() as u32, SVR_NODE_IDX);
-    assert_eq!(fft_idx.index() as u32, FFT_NODE_IDX);
-    assert_eq!(dac_idx.index() as u32, DAC_NODE_IDX);
-
-    node_map.insert(YOU_NODE, s_idx);
-    node_map.insert(END_NODE, e_idx);
-    node_map.insert(SVR_NODE, srv_idx);
-    node_map.insert(FFT_NODE, fft_idx);
-    node_map.insert(DAC_NODE, dac_idx);
-
-    for (source, target) in edges_raw {
-        let source_idx = *node_map
-            .entry(source)
-            .or_insert_with(|| graph.add_node(source));
-
-        let target_idx = *node_map
-            .entry(target)
-            .or_insert_with(|| graph.add_node(target));
-
-        graph.add_edge(source_idx, target_idx, ());
+pub struct Day11;
+
+impl Solution for Day11 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 11;
+
+    type Input<'a> = DiGraph<&'a str, ()>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        let mut edges_raw: Vec<(&str, &str)> = Vec::new();
+        for line in parsing::lines(input) {
+            let (start_node, targets) = line.split_once(line.text, ':')?;
+            if targets.trim().is_empty() {
+                return Err(line.error(targets, "Expected a device followed by its outputs"));
+            }
+
+            edges_raw.extend(
+                targets
+                    .split_ascii_whitespace()
+                    .map(|target| (start_node.trim(), target)),
+            );
+        }
+
+        let mut graph = DiGraph::<&str, ()>::new();
+        let mut node_map: HashMap<&str, NodeIndex> = HashMap::new();
+
+        let s_idx = graph.add_node(YOU_NODE);
+        let e_idx = graph.add_node(END_NODE);
+        let srv_idx = graph.add_node(SVR_NODE);
+        let fft_idx = graph.add_node(FFT_NODE);
+        let dac_idx = graph.add_node(DAC_NODE);
+
+        assert_eq!(s_idx.index() as u32, YOU_NODE_IDX);
+        assert_eq!(e_idx.index() as u32, END_IDX);
+        assert_eq!(srv_idx.index() as u32, SVR_NODE_IDX);
+        assert_eq!(fft_idx.index() as u32, FFT_NODE_IDX);
+        assert_eq!(dac_idx.index() as u32, DAC_NODE_IDX);
+
+        node_map.insert(YOU_NODE, s_idx);
+        node_map.insert(END_NODE, e_idx);
+        node_map.insert(SVR_NODE, srv_idx);
+        node_map.insert(FFT_NODE, fft_idx);
+        node_map.insert(DAC_NODE, dac_idx);
+
+        for (source, target) in edges_raw {
+            let source_idx = *node_map
+                .entry(source)
+                .or_insert_with(|| graph.add_node(source));
+
+            let target_idx = *node_map
+                .entry(target)
+                .or_insert_with(|| graph.add_node(target));
+
+            graph.add_edge(source_idx, target_idx, ());
+        }
+
+        Ok(graph)
     }
 
-    task_one(&graph);
-    task_two(&graph);
+    fn part_one(graph: &Self::Input<'_>) -> Result<usize> {
+        Ok(task_one(graph))
+    }
 
-    println!();
+    fn part_two(graph: &Self::Input<'_>) -> Result<usize> {
+        Ok(task_two(graph))
+    }
 }
 
-fn task_one(graph: &DiGraph<&str, ()>) {
+fn task_one(graph: &DiGraph<&str, ()>) -> usize {
     let start_idx = NodeIndex::new(YOU_NODE_IDX as usize);
     let end_idx = NodeIndex::new(END_IDX as usize);
 
-    let path_count =
-        all_simple_paths::<Vec<_>, _, RandomState>(graph, start_idx, end_idx, 0, None).count();
-    println!(
-        "Task 1: Amount of different paths between you and out: {}",
-        path_count
-    );
+    all_simple_paths::<Vec<_>, _, RandomState>(graph, start_idx, end_idx, 0, None).count()
 }
 
 fn count_paths_dag(
@@ -130,7 +119,7 @@ fn count_paths_dag(
     total_paths
 }
 
-fn task_two(graph: &DiGraph<&str, ()>) {
+fn task_two(graph: &DiGraph<&str, ()>) -> usize {
     let svr_idx = NodeIndex::new(SVR_NODE_IDX as usize);
     let end_idx = NodeIndex::new(END_IDX as usize);
     let dac_idx = NodeIndex::new(DAC_NODE_IDX as usize);
@@ -151,10 +140,47 @@ fn task_two(graph: &DiGraph<&str, ()>) {
     let paths_fft_out = get_count(fft_idx, end_idx);
     let oute_svr_dac_fft_out = paths_svr_dac * paths_dac_fft * paths_fft_out;
 
-    let total_paths = route_svr_fft_dac_out + oute_svr_dac_fft_out;
+    route_svr_fft_dac_out + oute_svr_dac_fft_out
+}
 
-    println!(
-        "Task 2: Amount of different paths between Server and Out over fft and dac: {}",
-        total_paths
-    );
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_one_example() {
+        let example = "\
+aaa: you hhh
+you: bbb ccc
+bbb: ddd eee
+ccc: ddd eee fff
+ddd: ggg
+eee: out
+fff: out
+ggg: out
+hhh: ccc fff iii
+iii: out
+";
+        assert_eq!(Day11::part_one(&Day11::parse(example).unwrap()).unwrap(), 5);
+    }
+
+    #[test]
+    fn part_two_example() {
+        let example = "\
+svr: aaa bbb
+aaa: fft
+fft: ccc
+bbb: tty
+tty: ccc
+ccc: ddd eee
+ddd: hub
+hub: fff
+eee: dac
+dac: fff
+fff: ggg hhh
+ggg: out
+hhh: out
+";
+        assert_eq!(Day11::part_two(&Day11::parse(example).unwrap()).unwrap(), 2);
+    }
 }
@@ -1,92 +1,182 @@
-use std::env;
-use std::fs;
-
-pub fn run() {
-    let args: Vec<String> = env::args().collect();
-    let is_test_mode = args.iter().any(|arg| arg == "--test");
-    const INPUT_BASE: &str = "src/2025/input/";
-
-    let filename = if is_test_mode {
-        format!("{}{}", INPUT_BASE, "day2_test.input")
-    } else {
-        format!("{}{}", INPUT_BASE, "day2.input")
-    };
-
-    println!("📆 Day 2 {}:", if is_test_mode { "(DEBUG)" } else { "" });
-    println!("------------");
-    let contents = fs::read_to_string(&filename)
-        .unwrap_or_else(|_| panic!("Failed to read file: {}", filename));
-    let ranges: Vec<&str> = contents.split(|char| char == ',').collect();
-
-    task_one(&ranges);
-    task_two(&ranges);
-    println!();
+use crate::error::{AocError, Result};
+use crate::parsing::Line;
+use crate::solution::Solution;
+
+pub struct Day2;
+
+/// How often the block of digits of an invalid id is repeated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Repeats {
+    Twice,
+    AtLeastTwice,
 }
 
-fn task_one(ranges: &Vec<&str>) {
-    let mut invalid_id_count: u64 = 0;
-    for range in ranges {
-        if let Some((range_start, range_end)) = range.split_once('-') {
-            let range_start: u64 = range_start.parse().expect("Failed to parse range start");
-            let range_end: u64 = range_end.parse().expect("Failed to parse range end");
-
-            for id in range_start..=range_end {
-                let id_string = id.to_string();
-                let (first_half, second_half) = id_string.split_at(id_string.len() / 2);
-                if first_half == second_half {
-                    invalid_id_count += id;
-                }
-            }
-        } else {
-            eprintln!("Unexpected range format.");
-            return;
-        }
+impl Solution for Day2 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 2;
+
+    type Input<'a> = Vec<(u128, u128)>;
+    type PartOne = u128;
+    type PartTwo = u128;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        let line = Line {
+            index: 0,
+            text: input.trim_end(),
+        };
+
+        line.text
+            .split(',')
+            .map(|range| line.range(range))
+            .collect()
     }
 
-    println!(
-        "Task 1 - The added up invalid ids are: {}",
-        invalid_id_count
-    );
+    fn part_one(ranges: &Self::Input<'_>) -> Result<u128> {
+        task(ranges, Repeats::Twice)
+    }
+
+    fn part_two(ranges: &Self::Input<'_>) -> Result<u128> {
+        task(ranges, Repeats::AtLeastTwice)
+    }
 }
 
-fn task_two(ranges: &Vec<&str>) {
-    let mut invalid_id_count: u64 = 0;
-    for range in ranges {
-        if let Some((range_start, range_end)) = range.split_once('-') {
-            let range_start: u64 = range_start.parse().expect("Failed to parse range start");
-            let range_end: u64 = range_end.parse().expect("Failed to parse range end");
-
-            for id in range_start..=range_end {
-                let id_string = id.to_string();
-                let length = id_string.len();
-
-                let valid_substring_lengths: Vec<usize> = (1..length)
-                    .filter(|substr_length| length % substr_length == 0)
-                    .collect();
-
-                for cut in valid_substring_lengths {
-                    let number = &id_string[..cut];
-                    let mut are_equal = true;
-                    for i in 1..(length / cut) {
-                        if number != &id_string[(i * cut)..(i + 1) * cut] {
-                            are_equal = false;
-                            break;
-                        }
-                    }
-                    if are_equal {
-                        invalid_id_count += id;
-                        break;
+fn task(ranges: &[(u128, u128)], repeats: Repeats) -> Result<u128> {
+    ranges.iter().try_fold(0u128, |sum, &(start, end)| {
+        invalid_id_sum(start, end, repeats)
+            .and_then(|range_sum| sum.checked_add(range_sum))
+            .ok_or_else(|| AocError::solver("Sum of the invalid ids overflows a u128"))
+    })
+}
+
+/// Sum of the invalid ids in `start..=end`, or `None` if it overflows.
+///
+/// Instead of checking every id, the invalid ones of each digit count are generated as a
+/// block times a repunit like `1001001`, so only the range of blocks has to be summed.
+fn invalid_id_sum(start: u128, end: u128, repeats: Repeats) -> Option<u128> {
+    let max_length = end.checked_ilog10().map_or(1, |log| log + 1);
+
+    let mut sum: u128 = 0;
+    for length in 2..=max_length {
+        let length_sum = match repeats {
+            Repeats::Twice if length.is_multiple_of(2) => {
+                periodic_sum(start, end, length, length / 2)?
+            }
+            Repeats::Twice => 0,
+            Repeats::AtLeastTwice => {
+                // An id repeating blocks of two lengths also repeats a block of their gcd,
+                // so the ids of every period are the union over the periods `length / p`
+                // for the primes p dividing `length`, and overlaps are periods of products.
+                let primes = prime_factors(length);
+                let (mut added, mut removed): (u128, u128) = (0, 0);
+                for subset in 1..1u32 << primes.len() {
+                    let divisor: u32 = (0..primes.len())
+                        .filter(|&i| subset & 1 << i != 0)
+                        .map(|i| primes[i])
+                        .product();
+                    let subset_sum = periodic_sum(start, end, length, length / divisor)?;
+                    if subset.count_ones() % 2 == 1 {
+                        added = added.checked_add(subset_sum)?;
+                    } else {
+                        removed = removed.checked_add(subset_sum)?;
                     }
                 }
+                added - removed
+            }
+        };
+        sum = sum.checked_add(length_sum)?;
+    }
+
+    Some(sum)
+}
+
+/// Sum of the ids in `start..=end` with `length` digits made of a block of `period` digits
+/// repeated.
+fn periodic_sum(start: u128, end: u128, length: u32, period: u32) -> Option<u128> {
+    let shift = 10u128.checked_pow(period)?;
+    let repunit =
+        (0..length / period).try_fold(0u128, |r, _| r.checked_mul(shift)?.checked_add(1))?;
+
+    let first_block = (shift / 10).max(start.div_ceil(repunit));
+    let last_block = (shift - 1).min(end / repunit);
+    if first_block > last_block {
+        return Some(0);
+    }
+
+    // Blocks have at most 19 digits, so their sum can only overflow once multiplied
+    let block_sum = (first_block + last_block) * (last_block - first_block + 1) / 2;
+    block_sum.checked_mul(repunit)
+}
+
+fn prime_factors(mut n: u32) -> Vec<u32> {
+    let mut primes = Vec::new();
+    let mut factor = 2;
+    while factor * factor <= n {
+        if n.is_multiple_of(factor) {
+            primes.push(factor);
+            while n.is_multiple_of(factor) {
+                n /= factor;
             }
-        } else {
-            eprintln!("Unexpected range format.");
-            return;
         }
+        factor += 1;
+    }
+    if n > 1 {
+        primes.push(n);
+    }
+    primes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,\
+1698522-1698528,446443-446449,38593856-38593862,565653-565659,\
+824824821-824824827,2121212118-2121212124";
+
+    #[test]
+    fn part_one_example() {
+        assert_eq!(
+            Day2::part_one(&Day2::parse(EXAMPLE).unwrap()).unwrap(),
+            1227775554
+        );
     }
 
-    println!(
-        "Task 2 - The added up invalid ids are: {}",
-        invalid_id_count
-    );
+    #[test]
+    fn part_two_example() {
+        assert_eq!(
+            Day2::part_two(&Day2::parse(EXAMPLE).unwrap()).unwrap(),
+            4174379265
+        );
+    }
+
+    fn is_invalid(id: u128, repeats: Repeats) -> bool {
+        let id = id.to_string();
+        let length = id.len();
+
+        (1..length)
+            .filter(|&period| length.is_multiple_of(period))
+            .filter(|&period| repeats == Repeats::AtLeastTwice || length == 2 * period)
+            .any(|period| {
+                id.as_bytes()
+                    .chunks(period)
+                    .all(|b| b == &id.as_bytes()[..period])
+            })
+    }
+
+    #[test]
+    fn matches_checking_every_id() {
+        for (start, end) in [(1, 200_000), (999_990, 1_010_101), (123_123, 123_124)] {
+            for repeats in [Repeats::Twice, Repeats::AtLeastTwice] {
+                let expected: u128 = (start..=end).filter(|&id| is_invalid(id, repeats)).sum();
+                assert_eq!(invalid_id_sum(start, end, repeats), Some(expected));
+            }
+        }
+    }
+
+    #[test]
+    fn handles_huge_ranges() {
+        assert!(invalid_id_sum(1, u64::MAX as u128, Repeats::AtLeastTwice).is_some());
+        // The 38 digit ids alone add up to far more than a u128 holds
+        assert!(invalid_id_sum(0, u128::MAX, Repeats::Twice).is_none());
+    }
 }
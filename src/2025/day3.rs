@@ -1,126 +1,133 @@
-use std::env;
-use std::fs;
-
-pub fn run() {
-    let args: Vec<String> = env::args().collect();
-    let is_test_mode = args.iter().any(|arg| arg == "--test");
-    const INPUT_BASE: &str = "src/2025/input/";
-
-    let filename = if is_test_mode {
-        format!("{}{}", INPUT_BASE, "day3_test.input")
-    } else {
-        format!("{}{}", INPUT_BASE, "day3.input")
-    };
-
-    println!("📆 Day 3 {}:", if is_test_mode { "(DEBUG)" } else { "" });
-    println!("------------");
-    let contents = fs::read_to_string(&filename)
-        .unwrap_or_else(|_| panic!("Failed to read file: {}", filename));
-    let battery_banks: Vec<&str> = contents.lines().collect();
-
-    task_one(&battery_banks);
-    task_two(&battery_banks);
-    println!();
+use num_bigint::BigUint;
+
+use crate::error::{AocError, Result};
+use crate::solution::{Answer, Solution};
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 3;
+
+    type Input<'a> = Vec<&'a str>;
+    type PartOne = Answer;
+    type PartTwo = Answer;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| match line.find(|c: char| !c.is_ascii_digit()) {
+                Some(offset) => Err(AocError::parse(
+                    i,
+                    line[..offset].chars().count() + 1,
+                    "Battery bank contains a non-digit joltage",
+                )),
+                None => Ok(line),
+            })
+            .collect()
+    }
+
+    fn part_one(battery_banks: &Self::Input<'_>) -> Result<Answer> {
+        total_joltage(battery_banks, 2)
+    }
+
+    fn part_two(battery_banks: &Self::Input<'_>) -> Result<Answer> {
+        total_joltage(battery_banks, 12)
+    }
 }
 
-fn task_one(battery_banks: &Vec<&str>) {
-    let mut joltages: Vec<u32> = Vec::new();
-    for battery_bank in battery_banks {
-        let mut idx_one = battery_bank.chars().nth(0).unwrap_or('0');
-        let mut idx_two = battery_bank.chars().nth(1).unwrap_or('0');
-        for current in battery_bank.chars().skip(2) {
-            if idx_two > idx_one {
-                (idx_one, idx_two) = (idx_two, current);
-            } else if current > idx_two {
-                idx_two = current;
-            }
-        }
+/// The largest number made of `k` digits of `bank`, kept in their order, as its digits.
+///
+/// Digits stay on a stack which only ever decreases from bottom to top, a larger digit
+/// replaces smaller ones before it as long as enough digits are left to still pick `k`.
+fn largest_joltage(bank: &[u8], k: usize) -> Vec<u8> {
+    let mut removable = bank.len().saturating_sub(k);
+    let mut digits: Vec<u8> = Vec::with_capacity(bank.len());
 
-        let joltage_str = format!("{}{}", idx_one, idx_two);
-        let joltage_result = joltage_str.parse::<u32>();
-
-        match joltage_result {
-            Ok(joltage) => {
-                joltages.push(joltage);
-            }
-            Err(e) => {
-                eprintln!(
-                    "Something went wrong while parsing '{}': {}",
-                    joltage_str, e
-                );
-            }
+    for &digit in bank {
+        while removable > 0 && digits.last().is_some_and(|&last| last < digit) {
+            digits.pop();
+            removable -= 1;
         }
+        digits.push(digit);
     }
+    digits.truncate(k);
 
-    println!(
-        "Task 1 - The total joltages are: {}",
-        joltages.iter().sum::<u32>()
-    )
+    digits
 }
 
-struct JoltageCell {
-    joltage: char,
+fn total_joltage(battery_banks: &[&str], k: usize) -> Result<Answer> {
+    let joltages = battery_banks
+        .iter()
+        .enumerate()
+        .map(|(i, battery_bank)| match battery_bank.len() {
+            length if length < k => Err(AocError::solver(format!(
+                "Battery bank {} has {} batteries, {} have to be turned on",
+                i + 1,
+                length,
+                k
+            ))),
+            _ => Ok(largest_joltage(battery_bank.as_bytes(), k)),
+        })
+        .collect::<Result<Vec<Vec<u8>>>>()?;
+
+    // Up to 38 digits fit into a u128, only larger sums need a big number
+    let small_sum = joltages.iter().try_fold(0u128, |sum, digits| {
+        let joltage = digits.iter().try_fold(0u128, |joltage, digit| {
+            joltage.checked_mul(10)?.checked_add((digit - b'0') as u128)
+        })?;
+        sum.checked_add(joltage)
+    });
+    if let Some(sum) = small_sum {
+        return Ok(sum.into());
+    }
+
+    let sum: BigUint = joltages
+        .iter()
+        .map(|digits| BigUint::parse_bytes(digits, 10).expect("Banks only contain digits"))
+        .sum();
+    Ok(sum.to_string().into())
 }
 
-fn task_two(battery_banks: &Vec<&str>) {
-    let mut joltages: Vec<u64> = Vec::new();
-    for battery_bank in battery_banks {
-        let mut joltage_cells: Vec<JoltageCell> = (0..12)
-            .map(|i| JoltageCell {
-                joltage: battery_bank.chars().nth(i).unwrap_or('0'),
-            })
-            .collect();
-
-        for current in battery_bank.chars().skip(12) {
-            let mut took_current = false;
-            for i in 0..11 {
-                if joltage_cells[i].joltage < joltage_cells[i + 1].joltage {
-                    joltage_cells.remove(i);
-                    joltage_cells.push(JoltageCell { joltage: current });
-                    took_current = true;
-                    break;
-                }
-            }
-
-            if !took_current {
-                match joltage_cells.last() {
-                    Some(last_cell) => {
-                        if last_cell.joltage < current {
-                            joltage_cells.pop();
-                            joltage_cells.push(JoltageCell { joltage: current });
-                        }
-                    }
-                    _ => {
-                        eprintln!(
-                            "Unexpected error: Did not find last joltage_cell in joltage_cells: {}",
-                            joltage_cells
-                                .iter()
-                                .map(|cell| cell.joltage)
-                                .collect::<String>()
-                        );
-                    }
-                }
-            }
-        }
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-        let joltage_result = joltage_cells
-            .iter()
-            .map(|cell| cell.joltage)
-            .collect::<String>()
-            .parse::<u64>();
-
-        match joltage_result {
-            Ok(joltage) => {
-                joltages.push(joltage);
-            }
-            Err(e) => {
-                eprintln!("Something went wrong while parsing: {}", e);
-            }
-        }
+    const EXAMPLE: &str = "\
+987654321111111
+811111111111119
+234234234234278
+818181911112111
+";
+
+    #[test]
+    fn part_one_example() {
+        assert_eq!(
+            Day3::part_one(&Day3::parse(EXAMPLE).unwrap()).unwrap(),
+            Answer::Number(357)
+        );
+    }
+
+    #[test]
+    fn part_two_example() {
+        assert_eq!(
+            Day3::part_two(&Day3::parse(EXAMPLE).unwrap()).unwrap(),
+            Answer::Number(3121910778619)
+        );
     }
 
-    println!(
-        "Task 2 - The total joltages are: {}",
-        joltages.iter().sum::<u64>()
-    )
+    #[test]
+    fn picks_largest_subsequence() {
+        assert_eq!(largest_joltage(b"818181911112111", 5), b"92111");
+        assert_eq!(largest_joltage(b"54321", 5), b"54321");
+
+        let banks = ["9".repeat(60)];
+        let banks: Vec<&str> = banks.iter().map(String::as_str).collect();
+        assert_eq!(
+            total_joltage(&banks, 50).unwrap(),
+            Answer::Text("9".repeat(50))
+        );
+        assert!(total_joltage(&banks, 61).is_err());
+    }
 }
@@ -1,209 +1,255 @@
-use std::env;
-use std::fs;
+use crate::error::Result;
+use crate::grid::{Grid, Pos};
+use crate::solution::{Part, Solution, Variant};
 
 const PAPER_ROLL_CHAR: char = '@';
 const REMOVED_ROL_CHAR: char = 'x';
 
-pub fn run() {
-    let args: Vec<String> = env::args().collect();
-    let is_test_mode = args.iter().any(|arg| arg == "--test");
-    const INPUT_BASE: &str = "src/2025/input/";
-
-    let filename = if is_test_mode {
-        format!("{}{}", INPUT_BASE, "day4_test.input")
-    } else {
-        format!("{}{}", INPUT_BASE, "day4.input")
-    };
-
-    println!("📆 Day 4 {}:", if is_test_mode { "(DEBUG)" } else { "" });
-    println!("------------");
-    let contents = fs::read_to_string(&filename)
-        .unwrap_or_else(|_| panic!("Failed to read file: {}", filename));
-    let diagram_lines: Vec<Vec<char>> = contents
-        .lines()
-        .map(|line| line.chars().collect())
-        .collect();
-
-    task_one(&diagram_lines);
-    task_two(&diagram_lines);
-    println!();
-}
+pub struct Day4;
 
-fn task_one(diagram_lines: &Vec<Vec<char>>) {
-    let x_dim = diagram_lines[0].len();
-    let y_dim = diagram_lines.len();
+impl Solution for Day4 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 4;
 
-    let mut diagram_lines_copy = diagram_lines.clone();
+    type Input<'a> = Grid<char>;
+    type PartOne = u32;
+    type PartTwo = u32;
 
-    let mut reachable_roll_count: u32 = 0;
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Grid::parse(input)
+    }
 
-    for i in 0..y_dim {
-        for j in 0..x_dim {
-            // Only check for actual paper rolls
-            if diagram_lines[i][j] != PAPER_ROLL_CHAR {
-                continue;
-            }
+    fn part_one(diagram: &Self::Input<'_>) -> Result<u32> {
+        Ok(task_one(diagram))
+    }
 
-            let mut adjacent_roll_count = 0;
-            for off_y in -1..=1 {
-                for off_x in -1..=1 {
-                    if off_y == 0 && off_x == 0 {
-                        continue;
-                    }
-                    let y = (i as isize) + off_y;
-                    let x = (j as isize) + off_x;
-
-                    // Don't check out of grid
-                    if y < 0 || y >= (y_dim as isize) || x < 0 || x >= (x_dim as isize) {
-                        continue;
-                    }
-
-                    if diagram_lines[y as usize][x as usize] == PAPER_ROLL_CHAR {
-                        adjacent_roll_count += 1;
-                    }
-                }
-            }
+    fn part_two(diagram: &Self::Input<'_>) -> Result<u32> {
+        Ok(task_two(diagram))
+    }
 
-            if adjacent_roll_count < 4 {
-                reachable_roll_count += 1;
-                diagram_lines_copy[i][j] = REMOVED_ROL_CHAR;
-            }
+    fn variants(part: Part) -> Vec<Variant<Self>> {
+        match part {
+            Part::One => Vec::new(),
+            Part::Two => vec![
+                ("task_two_worklist", |diagram| {
+                    Ok(task_two_worklist(diagram).into())
+                }),
+                ("task_two_alt", |diagram| Ok(task_two_alt(diagram).into())),
+            ],
         }
     }
 
-    println!(
-        "Task 1 - The reachable paper roll count is: {}",
-        reachable_roll_count
-    );
+    fn frames(diagram: &Self::Input<'_>) -> Vec<Grid<char>> {
+        removal_frames(diagram)
+    }
 }
 
-fn check_neighboring_fields(
-    curr_x: usize,
-    curr_y: usize,
-    x_dim: usize,
-    y_dim: usize,
-    mut callback: impl FnMut(usize, usize),
-) {
-    for off_y in -1..=1 {
-        for off_x in -1..=1 {
-            if off_y == 0 && off_x == 0 {
-                continue;
-            }
-            let y = (curr_y as isize) + off_y;
-            let x = (curr_x as isize) + off_x;
-
-            // Don't check out of grid
-            if y < 0 || y >= (y_dim as isize) || x < 0 || x >= (x_dim as isize) {
-                continue;
-            }
+fn adjacent_roll_count(diagram: &Grid<char>, pos: Pos) -> usize {
+    diagram
+        .neighbours8(pos)
+        .filter(|&neighbour| diagram[neighbour] == PAPER_ROLL_CHAR)
+        .count()
+}
 
-            callback(x as usize, y as usize);
-        }
-    }
+fn task_one(diagram: &Grid<char>) -> u32 {
+    diagram
+        .iter()
+        .filter(|&(pos, &field)| field == PAPER_ROLL_CHAR && adjacent_roll_count(diagram, pos) < 4)
+        .count() as u32
 }
 
 fn check_specific_indices_for_removal(
-    diagram_lines: &mut Vec<Vec<char>>,
-    working_set: &mut Vec<(usize, usize)>,
+    diagram: &mut Grid<char>,
+    working_set: &mut Vec<Pos>,
     reachable_roll_count: &mut u32,
-    (x, y): (usize, usize),
+    pos: Pos,
 ) {
-    let x_dim = diagram_lines[0].len();
-    let y_dim = diagram_lines.len();
-
-    if diagram_lines[y][x] != PAPER_ROLL_CHAR {
+    if diagram[pos] != PAPER_ROLL_CHAR || adjacent_roll_count(diagram, pos) >= 4 {
         return;
     }
 
-    let mut adjacent_roll_count = 0;
+    *reachable_roll_count += 1;
+    diagram[pos] = REMOVED_ROL_CHAR;
 
-    check_neighboring_fields(x, y, x_dim, y_dim, |x, y| {
-        if diagram_lines[y][x] == PAPER_ROLL_CHAR {
-            adjacent_roll_count += 1;
-        }
-    });
+    // Add neighboring indices to a working set as they need to be rechecked
+    working_set.extend(
+        diagram
+            .neighbours8(pos)
+            .filter(|&neighbour| diagram[neighbour] == PAPER_ROLL_CHAR),
+    );
+}
 
-    if adjacent_roll_count < 4 {
-        *reachable_roll_count += 1;
-        diagram_lines[y][x] = REMOVED_ROL_CHAR;
+fn mark_and_count_removable_rolls(
+    diagram: &mut Grid<char>,
+    working_set: &mut Vec<Pos>,
+    reachable_roll_count: &mut u32,
+) {
+    for pos in diagram.positions().collect::<Vec<_>>() {
+        check_specific_indices_for_removal(diagram, working_set, reachable_roll_count, pos);
+    }
+}
 
-        // Add neighboring indices to a working set as they need to be rechecked
-        check_neighboring_fields(x, y, x_dim, y_dim, |x, y| {
-            if diagram_lines[y][x] == PAPER_ROLL_CHAR {
-                working_set.push((x, y));
+/// The rolls removed in each wave: the first wave is every roll accessible from the start,
+/// each following one what became accessible by removing the wave before.
+pub fn removal_waves(diagram: &Grid<char>) -> Vec<Vec<Pos>> {
+    // Adjacent rolls are counted once and decremented as rolls go, rather than recounted
+    let mut adjacent_rolls = diagram.map(|_| 0u8);
+    let mut queued = diagram.map(|&field| field != PAPER_ROLL_CHAR);
+    let mut wave = Vec::new();
+
+    for (pos, &field) in diagram.iter() {
+        if field == PAPER_ROLL_CHAR {
+            adjacent_rolls[pos] = adjacent_roll_count(diagram, pos) as u8;
+            if adjacent_rolls[pos] < 4 {
+                queued[pos] = true;
+                wave.push(pos);
             }
-        });
+        }
     }
+
+    let mut waves = Vec::new();
+    while !wave.is_empty() {
+        let mut next_wave = Vec::new();
+        for &pos in &wave {
+            for neighbour in diagram.neighbours8(pos) {
+                if diagram[neighbour] != PAPER_ROLL_CHAR {
+                    continue;
+                }
+                adjacent_rolls[neighbour] -= 1;
+                if !queued[neighbour] && adjacent_rolls[neighbour] < 4 {
+                    queued[neighbour] = true;
+                    next_wave.push(neighbour);
+                }
+            }
+        }
+        waves.push(std::mem::replace(&mut wave, next_wave));
+    }
+
+    waves
 }
 
-fn mark_and_count_removable_rolls(
-    diagram_lines: &mut Vec<Vec<char>>,
-    working_set: &mut Vec<(usize, usize)>,
-    reachable_roll_count: &mut u32,
-) {
-    let x_dim = diagram_lines[0].len();
-    let y_dim = diagram_lines.len();
-
-    for i in 0..y_dim {
-        for j in 0..x_dim {
-            check_specific_indices_for_removal(
-                diagram_lines,
-                working_set,
-                reachable_roll_count,
-                (j, i),
-            );
+/// The diagram before each wave with its rolls marked as removed, and after the last one.
+fn removal_frames(diagram: &Grid<char>) -> Vec<Grid<char>> {
+    let mut frame = diagram.clone();
+    let mut frames = Vec::new();
+
+    for wave in removal_waves(diagram) {
+        for &pos in &wave {
+            frame[pos] = REMOVED_ROL_CHAR;
+        }
+        frames.push(frame.clone());
+        // Rolls removed in earlier waves are gone, only the latest wave stays marked
+        for &pos in &wave {
+            frame[pos] = '.';
         }
     }
+    frames.push(frame);
+
+    frames
 }
 
-pub fn task_two(diagram_lines: &Vec<Vec<char>>) {
-    let mut diagram_lines_copy = diagram_lines.clone();
+fn task_two(diagram: &Grid<char>) -> u32 {
+    removal_waves(diagram).iter().map(Vec::len).sum::<usize>() as u32
+}
+
+fn task_two_worklist(diagram: &Grid<char>) -> u32 {
+    let mut diagram_copy = diagram.clone();
     let mut reachable_roll_count: u32 = 0;
 
-    let mut working_set: Vec<(usize, usize)> = Vec::new();
+    let mut working_set: Vec<Pos> = Vec::new();
 
     mark_and_count_removable_rolls(
-        &mut diagram_lines_copy,
+        &mut diagram_copy,
         &mut working_set,
         &mut reachable_roll_count,
     );
 
-    while let Some(indices) = working_set.pop() {
+    while let Some(pos) = working_set.pop() {
         check_specific_indices_for_removal(
-            &mut diagram_lines_copy,
+            &mut diagram_copy,
             &mut working_set,
             &mut reachable_roll_count,
-            indices,
+            pos,
         );
     }
 
-    println!(
-        "Task 2 - The reachable paper roll count is: {}",
-        reachable_roll_count
-    );
+    reachable_roll_count
 }
 
-#[allow(dead_code)]
-pub fn task_two_alt(diagram_lines: &Vec<Vec<char>>) {
-    let mut diagram_lines_copy = diagram_lines.clone();
+fn task_two_alt(diagram: &Grid<char>) -> u32 {
+    let mut diagram_copy = diagram.clone();
     let mut reachable_roll_count: u32 = 0;
 
-    let mut working_set: Vec<(usize, usize)> = Vec::new();
-
-    for y in 0..diagram_lines.len() {
-        for x in 0..diagram_lines[0].len() {
-            if diagram_lines[y][x] == PAPER_ROLL_CHAR {
-                working_set.push((x, y));
-            }
-        }
-    }
+    let mut working_set: Vec<Pos> = diagram
+        .iter()
+        .filter(|&(_, &field)| field == PAPER_ROLL_CHAR)
+        .map(|(pos, _)| pos)
+        .collect();
 
-    while let Some(indices) = working_set.pop() {
+    while let Some(pos) = working_set.pop() {
         check_specific_indices_for_removal(
-            &mut diagram_lines_copy,
+            &mut diagram_copy,
             &mut working_set,
             &mut reachable_roll_count,
-            indices,
+            pos,
+        );
+    }
+
+    reachable_roll_count
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+..@@.@@@@.
+@@@.@.@.@@
+@@@@@.@.@@
+@.@@@@..@.
+@@.@@@@.@@
+.@@@@@@@.@
+.@.@.@.@@@
+@.@@@.@@@@
+.@@@@@@@@.
+@.@.@@@.@.
+";
+
+    #[test]
+    fn part_one_example() {
+        assert_eq!(Day4::part_one(&Day4::parse(EXAMPLE).unwrap()).unwrap(), 13);
+    }
+
+    #[test]
+    fn part_two_example() {
+        assert_eq!(Day4::part_two(&Day4::parse(EXAMPLE).unwrap()).unwrap(), 43);
+    }
+
+    #[test]
+    fn part_two_alt_example() {
+        assert_eq!(task_two_alt(&Day4::parse(EXAMPLE).unwrap()), 43);
+        assert_eq!(task_two_worklist(&Day4::parse(EXAMPLE).unwrap()), 43);
+    }
+
+    #[test]
+    fn removal_frames_example() {
+        let frames = removal_frames(&Day4::parse(EXAMPLE).unwrap());
+
+        assert_eq!(frames.len(), 10);
+        assert!(frames[0].to_string().starts_with("..xx.xx@x.\n"));
+        assert_eq!(
+            frames[9].to_string().matches(PAPER_ROLL_CHAR).count(),
+            EXAMPLE.matches(PAPER_ROLL_CHAR).count() - 43
+        );
+    }
+
+    #[test]
+    fn removal_waves_example() {
+        let waves = removal_waves(&Day4::parse(EXAMPLE).unwrap());
+
+        assert_eq!(
+            waves.iter().map(Vec::len).collect::<Vec<_>>(),
+            vec![13, 12, 7, 5, 2, 1, 1, 1, 1]
         );
     }
 }
@@ -1,100 +1,107 @@
-use std::env;
-use std::fs;
-
-pub fn run() {
-    let args: Vec<String> = env::args().collect();
-    let is_test_mode = args.iter().any(|arg| arg == "--test");
-    const INPUT_BASE: &str = "src/2025/input/";
-
-    let filename = if is_test_mode {
-        format!("{}{}", INPUT_BASE, "day5_test.input")
-    } else {
-        format!("{}{}", INPUT_BASE, "day5.input")
-    };
-
-    println!("📆 Day 5 {}:", if is_test_mode { "(DEBUG)" } else { "" });
-    println!("------------");
-    let contents = fs::read_to_string(&filename)
-        .unwrap_or_else(|_| panic!("Failed to read file: {}", filename));
-
-    let mut at_ids_section = false;
-    let mut ranges = Vec::new();
-    let mut ids = Vec::new();
-
-    for line in contents.lines() {
-        if at_ids_section {
-            ids.push(
-                line.parse::<u64>()
-                    .expect("Invalid Id could not be parsed."),
-            );
-            continue;
-        }
-
-        if line == "" {
-            at_ids_section = true;
-            continue;
-        }
-
-        ranges.push({
-            let (start, end) = line
-                .split_once("-")
-                .expect("Invalid range format (missing '-')");
-
-            (
-                start.parse().expect("Invalid range start number"),
-                end.parse().expect("Invalid range end number"),
-            )
-        });
+use crate::error::{AocError, Result};
+use crate::intervals::IntervalSet;
+use crate::parsing;
+use crate::solution::Solution;
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 5;
+
+    type Input<'a> = (IntervalSet, Vec<u64>);
+    type PartOne = u32;
+    type PartTwo = u128;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        let sections = parsing::sections(input);
+        let [range_lines, id_lines] = sections.as_slice() else {
+            return Err(AocError::parse(
+                0,
+                1,
+                "Expected ranges and ids separated by a blank line",
+            ));
+        };
+
+        let ranges = range_lines
+            .iter()
+            .map(|line| match line.range(line.text)? {
+                (start, end) if start > end => {
+                    Err(line.error(line.text, "Range ends before it starts"))
+                }
+                range => Ok(range),
+            })
+            .collect::<Result<_>>()?;
+        let ids = id_lines
+            .iter()
+            .map(|line| line.number(line.text))
+            .collect::<Result<_>>()?;
+
+        Ok((ranges, ids))
     }
 
-    task_one((&ranges, &ids));
-    task_two(ranges);
-    println!();
-}
-
-fn task_one((ranges, ids): (&[(u64, u64)], &[u64])) {
-    let mut fresh_ids_count = 0;
-
-    for id in ids {
-        for (a, b) in ranges {
-            if (*a..=*b).contains(id) {
-                fresh_ids_count += 1;
-                break;
-            }
-        }
+    fn part_one((ranges, ids): &Self::Input<'_>) -> Result<u32> {
+        Ok(task_one((ranges, ids)))
     }
 
-    println!("Task 1 - Number of fresh ids: {}", fresh_ids_count);
-}
-
-fn task_two(mut ranges: Vec<(u64, u64)>) {
-    if ranges.is_empty() {
-        println!("Task 2 - Number of fresh ids: 0");
-        return;
+    fn part_two((ranges, _): &Self::Input<'_>) -> Result<u128> {
+        Ok(task_two(ranges))
     }
+}
 
-    ranges.sort_by_key(|r| r.0);
-
-    let mut merged_ranges: Vec<(u64, u64)> = Vec::new();
+fn task_one((ranges, ids): (&IntervalSet, &[u64])) -> u32 {
+    ids.iter().filter(|&&id| ranges.contains(id)).count() as u32
+}
 
-    let (mut current_start, mut current_end) = ranges[0];
+fn task_two(ranges: &IntervalSet) -> u128 {
+    ranges.len()
+}
 
-    for &(next_start, next_end) in ranges.iter().skip(1) {
-        if next_start <= current_end {
-            current_end = current_end.max(next_end);
-        } else {
-            merged_ranges.push((current_start, current_end));
-            current_start = next_start;
-            current_end = next_end;
-        }
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+3-5
+10-14
+16-20
+12-18
+
+1
+5
+8
+11
+17
+32
+";
+
+    #[test]
+    fn part_one_example() {
+        assert_eq!(Day5::part_one(&Day5::parse(EXAMPLE).unwrap()).unwrap(), 3);
     }
 
-    merged_ranges.push((current_start, current_end));
-
-    let mut total_count: u64 = 0;
-    merged_ranges
-        .iter()
-        .for_each(|(s, e)| total_count += e - s + 1);
+    #[test]
+    fn part_two_example() {
+        assert_eq!(Day5::part_two(&Day5::parse(EXAMPLE).unwrap()).unwrap(), 14);
+    }
 
-    println!("Task 2 - Number of all valid fresh ids: {}", total_count);
+    #[test]
+    fn parse_error_points_at_token() {
+        assert!(matches!(
+            Day5::parse("3-5\n10-1x\n\n1\n"),
+            Err(AocError::Parse {
+                line: 2,
+                column: 4,
+                ..
+            })
+        ));
+        assert!(matches!(
+            Day5::parse("3-5\n5-3\n\n1\n"),
+            Err(AocError::Parse {
+                line: 2,
+                column: 1,
+                ..
+            })
+        ));
+    }
 }
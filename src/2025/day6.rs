@@ -1,128 +1,284 @@
-use std::env;
-use std::fs;
-
-const ADD_STRING: &str = "+";
-const MUL_STRING: &str = "*";
-
-pub fn run() {
-    let args: Vec<String> = env::args().collect();
-    let is_test_mode = args.iter().any(|arg| arg == "--test");
-    const INPUT_BASE: &str = "src/2025/input/";
-
-    let filename = if is_test_mode {
-        format!("{}{}", INPUT_BASE, "day6_test.input")
-    } else {
-        format!("{}{}", INPUT_BASE, "day6.input")
-    };
-
-    println!("📆 Day 6 {}:", if is_test_mode { "(DEBUG)" } else { "" });
-    println!("------------");
-    let file_content = fs::read_to_string(&filename)
-        .unwrap_or_else(|_| panic!("Failed to read file: {}", filename));
-
-    let lines: Vec<&str> = file_content.lines().collect();
-
-    let (numbers_strings_array, operations_array) = lines.split_at(lines.len() - 1);
-    let numbers_array: Vec<Vec<u64>> = numbers_strings_array
-        .iter()
-        .map(|l| {
-            l.split_ascii_whitespace()
-                .map(|n| n.parse::<u64>().expect("Couldn't parse numbers"))
-                .collect()
-        })
-        .collect();
+use std::ops::Range;
 
-    let operations: Vec<&str> = operations_array[0].split_ascii_whitespace().collect();
+use crate::error::{AocError, Result};
+use crate::grid::Grid;
+use crate::solution::Solution;
 
-    task_one(&numbers_array, &operations);
-    task_two(lines);
+pub struct Day6;
 
-    println!();
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operation {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+    Power,
+    Min,
+    Max,
 }
 
-fn task_one(numbers: &[Vec<u64>], operations: &[&str]) {
-    let mut result = 0;
-
-    for i in 0..operations.len() {
-        match operations[i] {
-            ADD_STRING => {
-                result += numbers.iter().fold(0, |acc, nums| {
-                    acc + nums
-                        .get(i)
-                        .expect("Something went wrong while adding up array entries")
-                })
-            }
-            MUL_STRING => {
-                result += numbers.iter().fold(1, |acc, nums| {
-                    acc * nums
-                        .get(i)
-                        .expect("Something went wrong while adding up array entries")
-                })
+impl Operation {
+    fn parse(token: &str) -> Option<Operation> {
+        match token {
+            "+" => Some(Operation::Add),
+            "-" => Some(Operation::Subtract),
+            "*" => Some(Operation::Multiply),
+            "/" => Some(Operation::Divide),
+            "^" => Some(Operation::Power),
+            "min" => Some(Operation::Min),
+            "max" => Some(Operation::Max),
+            _ => None,
+        }
+    }
+
+    /// Folds the numbers from left to right, e.g. `a - b - c` or `(a ^ b) ^ c`.
+    fn apply(self, numbers: &[u64]) -> Result<u64> {
+        let Some((&first, rest)) = numbers.split_first() else {
+            return Err(AocError::solver("Problem without numbers"));
+        };
+
+        rest.iter().try_fold(first, |result, &number| {
+            let value = match self {
+                Operation::Add => result.checked_add(number),
+                Operation::Subtract => result.checked_sub(number),
+                Operation::Multiply => result.checked_mul(number),
+                Operation::Divide => result.checked_div(number),
+                Operation::Power => u32::try_from(number)
+                    .ok()
+                    .and_then(|exponent| result.checked_pow(exponent)),
+                Operation::Min => Some(result.min(number)),
+                Operation::Max => Some(result.max(number)),
+            };
+            value.ok_or_else(|| {
+                AocError::solver(format!(
+                    "{:?} of {} and {} is not a u64",
+                    self, result, number
+                ))
+            })
+        })
+    }
+}
+
+/// One problem of the worksheet, a block of columns between blank ones.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Problem {
+    pub operation: Operation,
+    /// The numbers read left to right along each row, top to bottom.
+    pub rows: Vec<u64>,
+    /// The numbers read top to bottom along each column, right to left.
+    pub columns: Vec<u64>,
+}
+
+pub struct Worksheet {
+    pub problems: Vec<Problem>,
+}
+
+impl Solution for Day6 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 6;
+
+    type Input<'a> = Worksheet;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        // Padded, as trailing spaces of the last block might have been stripped
+        let sheet = Grid::parse_padded(input, ' ');
+        if sheet.height() < 2 {
+            return Err(AocError::parse(
+                sheet.height(),
+                1,
+                "Expected rows of numbers followed by an operations line",
+            ));
+        }
+
+        let blank: Vec<bool> = sheet.columns().map(|mut c| c.all(|&c| c == ' ')).collect();
+        let mut problems = Vec::new();
+        let mut x = 0;
+        while x < sheet.width() {
+            if blank[x] {
+                x += 1;
+                continue;
             }
-            _ => panic!("Unexpected operation: {}", operations[i]),
+            let end = (x..sheet.width())
+                .find(|&x| blank[x])
+                .unwrap_or(sheet.width());
+            problems.push(parse_problem(&sheet, x..end)?);
+            x = end;
         }
+
+        Ok(Worksheet { problems })
+    }
+
+    fn part_one(worksheet: &Self::Input<'_>) -> Result<u64> {
+        solve(worksheet, |problem| &problem.rows)
+    }
+
+    fn part_two(worksheet: &Self::Input<'_>) -> Result<u64> {
+        solve(worksheet, |problem| &problem.columns)
     }
+}
+
+/// The text of row `y` within `columns` without the surrounding spaces, and the 0-based
+/// column it starts at.
+fn token(sheet: &Grid<char>, y: usize, columns: &Range<usize>) -> (usize, String) {
+    let cells = &sheet.row(y)[columns.clone()];
+    let start = cells.iter().take_while(|&&c| c == ' ').count();
 
-    println!("Task 1 - The result of the homework is: {}", result);
+    (
+        columns.start + start,
+        cells[start..]
+            .iter()
+            .collect::<String>()
+            .trim_end()
+            .to_string(),
+    )
 }
 
-fn task_two(lines: Vec<&str>) {
-    if lines.is_empty() {
-        return;
+fn parse_problem(sheet: &Grid<char>, columns: Range<usize>) -> Result<Problem> {
+    let operations_index = sheet.height() - 1;
+
+    let (column, text) = token(sheet, operations_index, &columns);
+    let operation = Operation::parse(&text).ok_or_else(|| {
+        AocError::parse(
+            operations_index,
+            column + 1,
+            format!("Unexpected operation '{}'", text),
+        )
+    })?;
+
+    let mut rows = Vec::new();
+    for y in 0..operations_index {
+        let (column, text) = token(sheet, y, &columns);
+        if let Some((offset, c)) = text.chars().enumerate().find(|(_, c)| !c.is_ascii_digit()) {
+            return Err(AocError::parse(
+                y,
+                column + offset + 1,
+                format!("Expected a digit, found '{}'", c),
+            ));
+        }
+        rows.push(
+            text.parse::<u64>()
+                .map_err(|e| AocError::parse(y, column + 1, e.to_string()))?,
+        );
     }
 
-    let first_row = lines[0];
-
-    let result = {
-        let mut result: u64 = 0;
-        let mut current_numbers: Vec<u64> = vec![];
-        let mut current = String::new();
-
-        for i in (0..first_row.len()).rev() {
-            for j in 0..lines.len() {
-                let current_char = lines
-                    .get(j)
-                    .expect("Couldnt get line number")
-                    .chars()
-                    .nth(i)
-                    .unwrap_or(' ');
-
-                match current_char {
-                    ' ' => {
-                        if j == lines.len() - 1 && !current.is_empty() {
-                            current_numbers.push(current.parse::<u64>().unwrap_or_else(|_| {
-                                panic!("Couldn't parse current numbers string: {}", current)
-                            }));
-                            current.clear();
-                            continue;
-                        }
-                    }
-                    '+' => {
-                        current_numbers.push(current.parse::<u64>().unwrap_or_else(|_| {
-                            panic!("Couldn't parse current numbers string: {}", current)
-                        }));
-                        result += current_numbers.iter().sum::<u64>();
-                        current_numbers.clear();
-                        current.clear();
-                    }
-                    '*' => {
-                        current_numbers.push(current.parse::<u64>().unwrap_or_else(|_| {
-                            panic!("Couldn't parse current numbers string: {}", current)
-                        }));
-                        result += current_numbers.iter().fold(1, |acc, x| acc * x);
-                        current_numbers.clear();
-                        current.clear();
-                    }
-                    _ => {
-                        // Should be a number
-                        current.push(current_char);
-                    }
-                }
-            }
+    // The rows only hold digits and spaces by now, so every column is a valid number
+    let mut column_numbers = Vec::new();
+    for x in columns.rev() {
+        let digit_rows: Vec<usize> = (0..operations_index)
+            .filter(|&y| sheet[(x, y)] != ' ')
+            .collect();
+        // Columns only spanned by a longer operation like 'max' hold no number
+        if let Some(&first_row) = digit_rows.first() {
+            let digits: String = digit_rows.iter().map(|&y| sheet[(x, y)]).collect();
+            column_numbers.push(
+                digits
+                    .parse::<u64>()
+                    .map_err(|e| AocError::parse(first_row, x + 1, e.to_string()))?,
+            );
         }
+    }
+
+    Ok(Problem {
+        operation,
+        rows,
+        columns: column_numbers,
+    })
+}
+
+fn solve(worksheet: &Worksheet, numbers: impl Fn(&Problem) -> &[u64]) -> Result<u64> {
+    worksheet.problems.iter().try_fold(0u64, |total, problem| {
+        let result = problem.operation.apply(numbers(problem))?;
+        total
+            .checked_add(result)
+            .ok_or_else(|| AocError::solver("Grand total is not a u64"))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-        result
-    };
+    // Trailing spaces are significant for the column-wise reading of part two
+    const EXAMPLE: &str = "123 328  51 64 \n 45 64  387 23 \n  6 98  215 314\n*   +   *   +  \n";
 
-    println!("Task 2 - The result of the homework is: {}", result);
+    #[test]
+    fn part_one_example() {
+        assert_eq!(
+            Day6::part_one(&Day6::parse(EXAMPLE).unwrap()).unwrap(),
+            4277556
+        );
+    }
+
+    #[test]
+    fn part_two_example() {
+        assert_eq!(
+            Day6::part_two(&Day6::parse(EXAMPLE).unwrap()).unwrap(),
+            3263827
+        );
+    }
+
+    #[test]
+    fn splits_blocks_by_blank_columns() {
+        let worksheet = Day6::parse("10  2 \n 3 16 \n-  max\n").unwrap();
+
+        assert_eq!(
+            worksheet.problems,
+            vec![
+                Problem {
+                    operation: Operation::Subtract,
+                    rows: vec![10, 3],
+                    columns: vec![3, 1],
+                },
+                Problem {
+                    operation: Operation::Max,
+                    rows: vec![2, 16],
+                    columns: vec![26, 1],
+                },
+            ]
+        );
+        assert_eq!(Day6::part_one(&worksheet).unwrap(), 7 + 16);
+        assert_eq!(Day6::part_two(&worksheet).unwrap(), 2 + 26);
+    }
+
+    #[test]
+    fn more_operations() {
+        assert_eq!(Operation::Divide.apply(&[100, 5, 2]).unwrap(), 10);
+        assert_eq!(Operation::Power.apply(&[2, 3, 2]).unwrap(), 64);
+        assert_eq!(Operation::Min.apply(&[7, 3, 9]).unwrap(), 3);
+        assert!(Operation::Subtract.apply(&[3, 5]).is_err());
+        assert!(Operation::Divide.apply(&[3, 0]).is_err());
+    }
+
+    #[test]
+    fn errors_point_at_the_column() {
+        assert!(matches!(
+            Day6::parse("12 4x\n+  * \n"),
+            Err(AocError::Parse {
+                line: 1,
+                column: 5,
+                ..
+            })
+        ));
+        assert!(matches!(
+            Day6::parse("12 45\n+  % \n"),
+            Err(AocError::Parse {
+                line: 2,
+                column: 4,
+                ..
+            })
+        ));
+
+        // The number in the first column starts below the first row and is too large
+        let sheet = format!(" 1\n{}+ \n", "9 \n".repeat(21));
+        assert!(matches!(
+            Day6::parse(&sheet),
+            Err(AocError::Parse {
+                line: 2,
+                column: 1,
+                ..
+            })
+        ));
+    }
 }
@@ -1,71 +1,92 @@
 use std::collections::HashMap;
 use std::collections::HashSet;
-use std::env;
-use std::fs;
+
+use crate::error::{AocError, Result};
+use crate::grid::Grid;
+use crate::solution::Solution;
 
 const TACHYON_START: char = 'S';
 const TACHYON_SPLITTER: char = '^';
+const TACHYON_BEAM: char = '|';
 
-const INPUT_BASE: &str = "src/2025/input/";
-const CURR_DAY: u8 = 7;
-
-pub fn run() {
-    let args: Vec<String> = env::args().collect();
-    let is_test_mode = args.iter().any(|arg| arg == "--test");
-
-    let filename = if is_test_mode {
-        format!("{}{}{}{}", INPUT_BASE, "day", CURR_DAY, "_test.input")
-    } else {
-        format!("{}{}{}{}", INPUT_BASE, "day", CURR_DAY, ".input")
-    };
-
-    println!(
-        "📆 Day {}{}:",
-        CURR_DAY,
-        if is_test_mode { " (DEBUG)" } else { "" }
-    );
-    println!("------------");
-    let file_content = fs::read_to_string(&filename)
-        .unwrap_or_else(|_| panic!("Failed to read file: {}", filename));
-
-    let lines: Vec<&str> = file_content.lines().collect();
-
-    let (start, board) = lines.split_at(1);
-    let mut tachyon_index = 0;
-
-    for i in 0..start[0].len() {
-        if start[0]
-            .chars()
-            .nth(i)
-            .unwrap_or_else(|| panic!("Reached end of first line before finding tachyon start."))
-            == TACHYON_START
-        {
-            tachyon_index = i;
-            break;
-        }
+pub struct Day7;
+
+impl Solution for Day7 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 7;
+
+    type Input<'a> = (usize, Grid<char>);
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        let board = Grid::parse(input)?;
+
+        let tachyon_index = board
+            .rows()
+            .next()
+            .and_then(|start| start.iter().position(|&c| c == TACHYON_START))
+            .ok_or_else(|| AocError::parse(0, 1, "First line has no tachyon start"))?;
+
+        Ok((tachyon_index, board))
+    }
+
+    fn part_one((start, board): &Self::Input<'_>) -> Result<u64> {
+        Ok(task_one(*start, board))
+    }
+
+    fn part_two((start, board): &Self::Input<'_>) -> Result<u64> {
+        Ok(task_two(*start, board))
+    }
+
+    fn frames((start, board): &Self::Input<'_>) -> Vec<Grid<char>> {
+        beam_frames(*start, board)
     }
+}
+
+/// The columns a beam hitting a splitter at `index` continues in, beams leaving the board end.
+fn split(index: usize, width: usize) -> impl Iterator<Item = usize> {
+    index
+        .checked_sub(1)
+        .into_iter()
+        .chain(Some(index + 1).filter(|&i| i < width))
+}
+
+/// The board with the beams drawn in, one frame per row they advanced.
+fn beam_frames(start: usize, board: &Grid<char>) -> Vec<Grid<char>> {
+    let mut frame = board.clone();
+    let mut frames = vec![frame.clone()];
+    let mut active_indices = HashSet::from([start]);
 
-    task_one(tachyon_index, board);
-    task_two(tachyon_index, board);
+    for y in 1..board.height() {
+        let mut next_indices = HashSet::new();
+        for &index in &active_indices {
+            if board[(index, y)] == TACHYON_SPLITTER {
+                next_indices.extend(split(index, board.width()));
+            } else {
+                next_indices.insert(index);
+            }
+        }
+        for &index in &next_indices {
+            frame[(index, y)] = TACHYON_BEAM;
+        }
+        frames.push(frame.clone());
+        active_indices = next_indices;
+    }
 
-    println!();
+    frames
 }
 
-fn task_one(start: usize, board: &[&str]) {
+fn task_one(start: usize, board: &Grid<char>) -> u64 {
     let mut active_indices = HashSet::from([start]);
     let mut times_split: u64 = 0;
 
-    for line in board.iter() {
+    for line in board.rows().skip(1) {
         let mut next_indices = vec![];
         for &index in &active_indices {
-            match line
-                .chars()
-                .nth(index)
-                .expect("Couldn't visit active index")
-            {
+            match line[index] {
                 TACHYON_SPLITTER => {
-                    next_indices.push(index - 1);
-                    next_indices.push(index + 1);
+                    next_indices.extend(split(index, line.len()));
                     times_split += 1;
                 }
                 _ => {
@@ -77,34 +98,25 @@ fn task_one(start: usize, board: &[&str]) {
         active_indices.extend(next_indices);
     }
 
-    println!(
-        "Task 1 - Amount of times tachyons were split: {}",
-        times_split
-    );
+    times_split
 }
 
-fn task_two(start: usize, board: &[&str]) {
+fn task_two(start: usize, board: &Grid<char>) -> u64 {
     let mut active_indices: HashMap<usize, u64> = HashMap::new();
     active_indices.insert(start, 1);
     let mut timelines: u64 = 1;
 
-    for line in board.iter() {
+    for line in board.rows().skip(1) {
         let mut new_indices = HashMap::new();
         for (&index, &count) in active_indices.iter() {
-            match line
-                .chars()
-                .nth(index)
-                .expect("Couldn't visit active index")
-            {
+            match line[index] {
                 TACHYON_SPLITTER => {
-                    new_indices
-                        .entry(index - 1)
-                        .and_modify(|timelines| *timelines += count)
-                        .or_insert(count);
-                    new_indices
-                        .entry(index + 1)
-                        .and_modify(|timelines| *timelines += count)
-                        .or_insert(count);
+                    for next in split(index, line.len()) {
+                        new_indices
+                            .entry(next)
+                            .and_modify(|timelines| *timelines += count)
+                            .or_insert(count);
+                    }
                     timelines += count;
                 }
                 _ => {
@@ -118,8 +130,60 @@ fn task_two(start: usize, board: &[&str]) {
         active_indices = new_indices;
     }
 
-    println!(
-        "Task 2 - Amount of times tachyons split the time: {}",
-        timelines
-    );
+    timelines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+.......S.......
+...............
+.......^.......
+...............
+......^.^......
+...............
+.....^.^.^.....
+...............
+....^.^...^....
+...............
+...^.^...^.^...
+...............
+..^...^.....^..
+...............
+.^.^.^.^.^...^.
+...............
+";
+
+    #[test]
+    fn part_one_example() {
+        assert_eq!(Day7::part_one(&Day7::parse(EXAMPLE).unwrap()).unwrap(), 21);
+    }
+
+    #[test]
+    fn beam_frames_example() {
+        let (start, board) = Day7::parse(EXAMPLE).unwrap();
+        let frames = beam_frames(start, &board);
+
+        assert_eq!(frames.len(), board.height());
+        assert_eq!(
+            frames.last().unwrap().row(4).iter().collect::<String>(),
+            ".....|^|^|....."
+        );
+    }
+
+    #[test]
+    fn part_two_example() {
+        assert_eq!(Day7::part_two(&Day7::parse(EXAMPLE).unwrap()).unwrap(), 40);
+    }
+
+    #[test]
+    fn beams_leave_at_the_edges() {
+        for board in ["S.\n^.\n..\n", ".S\n.^\n..\n"] {
+            let input = Day7::parse(board).unwrap();
+            assert_eq!(Day7::part_one(&input).unwrap(), 1);
+            assert_eq!(Day7::part_two(&input).unwrap(), 2);
+        }
+    }
 }
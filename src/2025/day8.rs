@@ -1,188 +1,111 @@
-use std::collections::HashMap;
-use std::env;
-use std::fs;
-
-const INPUT_BASE: &str = "src/2025/input/";
-const CURR_DAY: u8 = 8;
-type Coordinate = (u32, u32, u32);
-
-pub fn run() {
-    let args: Vec<String> = env::args().collect();
-    let is_test_mode = args.iter().any(|arg| arg == "--test");
-
-    let filename = if is_test_mode {
-        format!("{}{}{}{}", INPUT_BASE, "day", CURR_DAY, "_test.input")
-    } else {
-        format!("{}{}{}{}", INPUT_BASE, "day", CURR_DAY, ".input")
-    };
-
-    println!(
-        "📆 Day {}{}:",
-        CURR_DAY,
-        if is_test_mode { " (DEBUG)" } else { "" }
-    );
-    println!("------------");
-    let file_content = fs::read_to_string(&filename)
-        .unwrap_or_else(|_| panic!("Failed to read file: {}", filename));
-
-    let coordinates: Vec<Coordinate> = file_content
-        .lines()
-        .map(|l| {
-            let nums: Vec<u32> = l
-                .split(',')
-                .map(|n| n.trim().parse().expect("Parse error"))
-                .collect();
-
-            match nums.as_slice() {
-                [x, y, z] => (*x, *y, *z),
-                _ => panic!("Line did not contain exactly 3 numbers"),
-            }
-        })
-        .collect();
-
-    task_one(&coordinates);
-    task_two(&coordinates);
-
-    println!();
-}
-
-fn euclid_distance(p1: Coordinate, p2: Coordinate) -> f64 {
-    let (x1, y1, z1) = p1;
-    let (x2, y2, z2) = p2;
-
-    let dx = x2 as f64 - x1 as f64;
-    let dy = y2 as f64 - y1 as f64;
-    let dz = z2 as f64 - z1 as f64;
-
-    (dx.powi(2) + dy.powi(2) + dz.powi(2)).sqrt()
-}
-
-fn task_one(coordinates: &[Coordinate]) {
-    let mut distances_vector = Vec::new();
-
-    for (i, &p1) in coordinates.iter().enumerate() {
-        for &p2 in &coordinates[i + 1..] {
-            let dist = euclid_distance(p1, p2);
-            distances_vector.push((p1, p2, dist));
-        }
+use crate::error::{AocError, Result};
+use crate::parsing;
+use crate::solution::Solution;
+use crate::spatial;
+use crate::union_find::UnionFind;
+
+type Coordinate = [i64; 3];
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 8;
+
+    type Input<'a> = Vec<Coordinate>;
+    type PartOne = usize;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parsing::lines(input)
+            .map(|line| {
+                let coordinate: [u32; 3] = line.tuple(line.text, ',')?;
+                Ok(coordinate.map(i64::from))
+            })
+            .collect()
     }
 
-    distances_vector.sort_by(|a, b| a.2.partial_cmp(&b.2).unwrap());
-
-    let mut groupings: HashMap<Coordinate, u32> = HashMap::new();
-    let mut next_group_id = 1;
-    let coordinate_count = coordinates.len();
-
-    for (c1, c2, _) in distances_vector.into_iter().take(coordinate_count) {
-        let g1 = groupings.get(&c1).copied();
-        let g2 = groupings.get(&c2).copied();
-        match (g1, g2) {
-            // CASE A: Neither point is in a group yet.
-            (None, None) => {
-                groupings.insert(c1, next_group_id);
-                groupings.insert(c2, next_group_id);
-                next_group_id += 1;
-            }
-
-            // CASE B: One point is in a group, the other is not.
-            (Some(id), None) => {
-                groupings.insert(c2, id);
-            }
-            (None, Some(id)) => {
-                groupings.insert(c1, id);
-            }
-
-            // CASE C: Both are already in the SAME group.
-            (Some(id1), Some(id2)) if id1 == id2 => {
-                continue;
-            }
-
-            // CASE D: They are in DIFFERENT groups - merge them
-            (Some(id1), Some(id2)) => {
-                for value in groupings.values_mut() {
-                    if *value == id2 {
-                        *value = id1;
-                    }
-                }
-            }
-        }
+    fn part_one(coordinates: &Self::Input<'_>) -> Result<usize> {
+        Ok(task_one(coordinates, coordinates.len()))
     }
 
-    let mut group_sizes: HashMap<u32, usize> = HashMap::new();
+    fn part_two(coordinates: &Self::Input<'_>) -> Result<u64> {
+        task_two(coordinates)
+    }
+}
 
-    for &group_id in groupings.values() {
-        *group_sizes.entry(group_id).or_default() += 1;
+fn task_one(coordinates: &[Coordinate], connections: usize) -> usize {
+    let mut circuits = UnionFind::new(coordinates.len());
+    // Only the closest pairs are ever looked at, so they are found lazily
+    for (_, i, j) in spatial::closest_pairs(coordinates).take(connections) {
+        circuits.union(i, j);
     }
 
-    let mut sizes: Vec<usize> = group_sizes.into_values().collect();
+    let mut sizes = circuits.component_sizes();
     sizes.sort_unstable_by(|a, b| b.cmp(a));
 
-    let top_3_mul: usize = sizes.iter().take(3).copied().fold(1, |acc, x| acc * x);
-    println!(
-        "Task 1 - The result of the three largest groupings: {}",
-        top_3_mul
-    );
+    sizes.iter().take(3).product()
 }
 
-fn task_two(coordinates: &[Coordinate]) {
-    let mut distances_vector = Vec::new();
+fn task_two(coordinates: &[Coordinate]) -> Result<u64> {
+    let mut circuits = UnionFind::new(coordinates.len());
 
-    for (i, &p1) in coordinates.iter().enumerate() {
-        for &p2 in &coordinates[i + 1..] {
-            let dist = euclid_distance(p1, p2);
-            distances_vector.push((p1, p2, dist));
+    for (_, i, j) in spatial::closest_pairs(coordinates) {
+        if circuits.union(i, j) && circuits.component_count() == 1 {
+            // Parsed from u32, so the product of two x values always fits a u64
+            return Ok(coordinates[i][0] as u64 * coordinates[j][0] as u64);
         }
     }
 
-    distances_vector.sort_by(|a, b| a.2.partial_cmp(&b.2).unwrap());
-
-    let mut groupings: HashMap<Coordinate, u32> = HashMap::new();
-    let mut next_group_id = 1;
-
-    let mut last_connected: Option<(Coordinate, Coordinate)> = None;
-
-    for (c1, c2, _) in distances_vector.into_iter() {
-        let g1 = groupings.get(&c1).copied();
-        let g2 = groupings.get(&c2).copied();
-        match (g1, g2) {
-            // CASE A: Neither point is in a group yet.
-            (None, None) => {
-                groupings.insert(c1, next_group_id);
-                groupings.insert(c2, next_group_id);
-                next_group_id += 1;
-            }
-
-            // CASE B: One point is in a group, the other is not.
-            (Some(id), None) => {
-                groupings.insert(c2, id);
-            }
-            (None, Some(id)) => {
-                groupings.insert(c1, id);
-            }
-
-            // CASE C: Both are already in the SAME group.
-            (Some(id1), Some(id2)) if id1 == id2 => {
-                continue;
-            }
-
-            // CASE D: They are in DIFFERENT groups - merge them
-            (Some(id1), Some(id2)) => {
-                for value in groupings.values_mut() {
-                    if *value == id2 {
-                        *value = id1;
-                    }
-                }
-
-                last_connected = Some((c1, c2));
-            }
-        }
+    Err(AocError::solver(
+        "Junction boxes never formed a single circuit",
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+162,817,812
+57,618,57
+906,360,560
+592,479,940
+352,342,300
+466,668,158
+542,29,236
+431,825,988
+739,650,466
+52,470,668
+216,146,977
+819,987,18
+117,168,530
+805,96,715
+346,949,466
+970,615,88
+941,993,340
+862,61,35
+984,92,344
+425,690,689
+";
+
+    #[test]
+    fn part_one_example() {
+        // The example only connects the ten closest pairs
+        assert_eq!(task_one(&Day8::parse(EXAMPLE).unwrap(), 10), 40);
     }
 
-    let wall_distance: u64 = if let Some((c1, c2)) = last_connected.take() {
-        c1.0 as u64 * c2.0 as u64
-    } else {
-        panic!("Didn't find last match.");
-    };
+    #[test]
+    fn part_two_example() {
+        assert_eq!(
+            Day8::part_two(&Day8::parse(EXAMPLE).unwrap()).unwrap(),
+            25272
+        );
+    }
 
-    println!("Task 2 - The distance from the wall is: {}", wall_distance);
+    #[test]
+    fn multiplies_large_coordinates() {
+        let coordinates = Day8::parse("4294967295,0,0\n4294967295,1,0\n").unwrap();
+        assert_eq!(Day8::part_two(&coordinates).unwrap(), 18446744065119617025);
+    }
 }
@@ -1,84 +1,58 @@
-use std::env;
-use std::fs;
-
-const INPUT_BASE: &str = "src/2025/input/";
-const CURR_DAY: u8 = 9;
+use crate::error::{AocError, Result};
+use crate::parsing;
+use crate::solution::Solution;
 
 type Coordinate = (u32, u32);
 
-pub fn run() {
-    let args: Vec<String> = env::args().collect();
-    let is_test_mode = args.iter().any(|arg| arg == "--test");
-
-    let filename = if is_test_mode {
-        format!("{}{}{}{}", INPUT_BASE, "day", CURR_DAY, "_test.input")
-    } else {
-        format!("{}{}{}{}", INPUT_BASE, "day", CURR_DAY, ".input")
-    };
-
-    println!(
-        "📆 Day {}{}:",
-        CURR_DAY,
-        if is_test_mode { " (DEBUG)" } else { "" }
-    );
-    println!("------------");
-    let file_content = fs::read_to_string(&filename)
-        .unwrap_or_else(|_| panic!("Failed to read file: {}", filename));
-
-    let coordinates: Vec<Coordinate> = file_content
-        .lines()
-        .map(|l| {
-            let nums: Vec<u32> = l
-                .split(',')
-                .map(|n| n.trim().parse().expect("Parse error"))
-                .collect();
-
-            match nums.as_slice() {
-                [x, y] => (*x, *y),
-                _ => panic!("Line did not contain exactly 2 numbers"),
-            }
-        })
-        .collect();
+pub struct Day9;
+
+impl Solution for Day9 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 9;
+
+    type Input<'a> = Vec<Coordinate>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parsing::lines(input)
+            .map(|line| {
+                let [x, y] = line.tuple(line.text, ',')?;
+                Ok((x, y))
+            })
+            .collect()
+    }
 
-    task_one(&coordinates);
-    task_two(&coordinates);
+    fn part_one(coordinates: &Self::Input<'_>) -> Result<u64> {
+        task_one(coordinates)
+    }
 
-    println!();
+    fn part_two(coordinates: &Self::Input<'_>) -> Result<u64> {
+        Ok(task_two(coordinates))
+    }
 }
 
-#[derive(Debug)]
-struct DistanceSquare {
-    c1: Coordinate,
-    c2: Coordinate,
-    area: u64,
+fn rectangle_area(c1: Coordinate, c2: Coordinate) -> u64 {
+    (c1.0.abs_diff(c2.0) as u64 + 1) * (c1.1.abs_diff(c2.1) as u64 + 1)
 }
 
-fn task_one(coordinates: &[Coordinate]) {
-    let mut distance_squares: Vec<DistanceSquare> = vec![];
+fn task_one(coordinates: &[Coordinate]) -> Result<u64> {
+    let mut areas: Vec<u64> = vec![];
 
     for (i, &c1) in coordinates.iter().enumerate() {
         for &c2 in &coordinates[i + 1..] {
-            distance_squares.push(DistanceSquare {
-                c1: c1,
-                c2: c2,
-                area: (c1.0.abs_diff(c2.0) as u64 + 1) * (c1.1.abs_diff(c2.1) as u64 + 1),
-            });
+            areas.push(rectangle_area(c1, c2));
         }
     }
 
-    distance_squares.sort_by(|a, b| b.area.partial_cmp(&a.area).unwrap());
-
-    let biggest_square = distance_squares
-        .first()
-        .expect("Need to have at least one rectangle.");
-    println!(
-        "Task 1 - The largest square is {} with tiles {:?}, {:?}",
-        biggest_square.area, biggest_square.c1, biggest_square.c2
-    )
+    areas
+        .into_iter()
+        .max()
+        .ok_or_else(|| AocError::solver("Need to have at least one rectangle"))
 }
 
-fn task_two(coordinates: &[Coordinate]) {
-    let mut distance_squares: Vec<DistanceSquare> = vec![];
+fn task_two(coordinates: &[Coordinate]) -> u64 {
+    let mut areas: Vec<u64> = vec![];
     let mut edges: Vec<(Coordinate, Coordinate)> = vec![];
 
     let coordinate_count = coordinates.len();
@@ -93,33 +67,12 @@ fn task_two(coordinates: &[Coordinate]) {
             let y_min = std::cmp::min(c1.1, c2.1);
             let y_max = std::cmp::max(c1.1, c2.1);
 
-            let top = ((x_min, y_max), (x_max, y_max));
-            let bottom = ((x_min, y_min), (x_max, y_min));
-            let left = ((x_min, y_min), (x_min, y_max));
-            let right = ((x_max, y_min), (x_max, y_max));
-            let square_sides = [top, bottom, left, right];
-
             let mut valid = true;
 
-            // Check if boundaries cross
-            for &(edge_p1, edge_p2) in edges.iter() {
-                for side in square_sides {
-                    if lines_intersect_strict(edge_p1, edge_p2, side.0, side.1) {
-                        valid = false;
-                        break;
-                    }
-                }
-                if !valid {
-                    break;
-                }
-            }
-            if !valid {
-                continue;
-            }
-
-            // Check that no node is within the rectangle itself
-            for &coord in coordinates {
-                if is_point_in_rect(coord, (x_min, y_min), (x_max, y_max)) {
+            // Check that no edge runs through the inside of the rectangle. This also
+            // catches edges that only touch the rectangle sides at their end points.
+            for &edge in edges.iter() {
+                if edge_crosses_rect(edge, (x_min, y_min), (x_max, y_max)) {
                     valid = false;
                     break;
                 }
@@ -137,33 +90,21 @@ fn task_two(coordinates: &[Coordinate]) {
             }
 
             if valid {
-                distance_squares.push(DistanceSquare {
-                    c1,
-                    c2,
-                    area: (c1.0.abs_diff(c2.0) as u64 + 1) * (c1.1.abs_diff(c2.1) as u64 + 1),
-                });
+                areas.push(rectangle_area(c1, c2));
             }
         }
     }
 
-    distance_squares.sort_by(|a, b| b.area.partial_cmp(&a.area).unwrap());
-
-    if let Some(biggest_square) = distance_squares.first() {
-        println!(
-            "Task 2 - The largest square is {} with tiles {:?}, {:?}",
-            biggest_square.area, biggest_square.c1, biggest_square.c2
-        )
-    } else {
-        println!("Task 2 - No valid square found");
-    }
+    // No valid square is reported as an area of 0
+    areas.into_iter().max().unwrap_or(0)
 }
 
-fn is_point_in_polygon(x: f64, y: f64, edges: &Vec<(Coordinate, Coordinate)>) -> bool {
+fn is_point_in_polygon(x: f64, y: f64, edges: &[(Coordinate, Coordinate)]) -> bool {
     let mut inside = false;
-    for i in 0..edges.len() {
+    for edge in edges {
         let ((xi, yi), (xj, yj)) = (
-            (edges[i].0 .0 as f64, edges[i].0 .1 as f64),
-            (edges[i].1 .0 as f64, edges[i].1 .1 as f64),
+            (edge.0 .0 as f64, edge.0 .1 as f64),
+            (edge.1 .0 as f64, edge.1 .1 as f64),
         );
 
         let intersect = ((yi > y) != (yj > y)) && (x < (xj - xi) * (y - yi) / (yj - yi) + xi);
@@ -174,34 +115,35 @@ fn is_point_in_polygon(x: f64, y: f64, edges: &Vec<(Coordinate, Coordinate)>) ->
     inside
 }
 
-// Check if a point is strictly inside a rectangle
-fn is_point_in_rect(p: Coordinate, r_min: Coordinate, r_max: Coordinate) -> bool {
-    p.0 > r_min.0 && p.0 < r_max.0 && p.1 > r_min.1 && p.1 < r_max.1
+// Check if an axis-aligned edge overlaps the strict inside of a rectangle
+fn edge_crosses_rect(edge: (Coordinate, Coordinate), r_min: Coordinate, r_max: Coordinate) -> bool {
+    let ((x1, y1), (x2, y2)) = edge;
+
+    x1.max(x2) > r_min.0 && x1.min(x2) < r_max.0 && y1.max(y2) > r_min.1 && y1.min(y2) < r_max.1
 }
 
-fn orientation(p: (u32, u32), q: (u32, u32), r: (u32, u32)) -> i8 {
-    let val = (q.1 as i64 - p.1 as i64) * (r.0 as i64 - q.0 as i64)
-        - (q.0 as i64 - p.0 as i64) * (r.1 as i64 - q.1 as i64);
-    if val == 0 {
-        return 0;
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+7,1
+11,1
+11,7
+9,7
+9,5
+2,5
+2,3
+7,3
+";
+
+    #[test]
+    fn part_one_example() {
+        assert_eq!(Day9::part_one(&Day9::parse(EXAMPLE).unwrap()).unwrap(), 50);
     }
-    if val > 0 {
-        return 1;
-    }
-    2
-}
 
-fn lines_intersect_strict(p1: (u32, u32), p2: (u32, u32), p3: (u32, u32), p4: (u32, u32)) -> bool {
-    let o1 = orientation(p1, p2, p3);
-    let o2 = orientation(p1, p2, p4);
-    let o3 = orientation(p3, p4, p1);
-    let o4 = orientation(p3, p4, p2);
-    if o1 != o2 && o3 != o4 {
-        // Need to be true intersections, not just touches
-        if o1 == 0 || o2 == 0 || o3 == 0 || o4 == 0 {
-            return false;
-        }
-        return true;
+    #[test]
+    fn part_two_example() {
+        assert_eq!(Day9::part_two(&Day9::parse(EXAMPLE).unwrap()).unwrap(), 24);
     }
-    false
 }
@@ -0,0 +1,173 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::solution::{Answer, Part};
+
+pub const DEFAULT_ANSWERS_FILE: &str = "answers.txt";
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct AnswerKey {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    pub input: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong { expected: String },
+    Unknown,
+}
+
+/// Known answers, stored as one tab separated `year day part input answer` line each.
+pub struct AnswerStore {
+    path: PathBuf,
+    entries: BTreeMap<AnswerKey, String>,
+}
+
+impl AnswerStore {
+    pub fn load(path: &Path) -> io::Result<AnswerStore> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e),
+        };
+
+        let mut entries = BTreeMap::new();
+        for (i, line) in contents.lines().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (key, answer) = parse_line(line).ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("{}:{}: malformed answer line", path.display(), i + 1),
+                )
+            })?;
+            entries.insert(key, answer);
+        }
+
+        Ok(AnswerStore {
+            path: path.to_path_buf(),
+            entries,
+        })
+    }
+
+    pub fn check(&self, key: &AnswerKey, answer: &Answer) -> Verdict {
+        match self.entries.get(key) {
+            Some(expected) if *expected == answer.to_string() => Verdict::Correct,
+            Some(expected) => Verdict::Wrong {
+                expected: expected.clone(),
+            },
+            None => Verdict::Unknown,
+        }
+    }
+
+    pub fn record(&mut self, key: AnswerKey, answer: &Answer) {
+        self.entries.insert(key, answer.to_string());
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        let mut contents = String::from("# year\tday\tpart\tinput\tanswer\n");
+        for (key, answer) in &self.entries {
+            contents.push_str(&format!(
+                "{}\t{}\t{}\t{}\t{}\n",
+                key.year, key.day, key.part, key.input, answer
+            ));
+        }
+
+        fs::write(&self.path, contents)
+    }
+}
+
+fn parse_line(line: &str) -> Option<(AnswerKey, String)> {
+    let mut fields = line.split('\t');
+
+    let year = fields.next()?.parse().ok()?;
+    let day = fields.next()?.parse().ok()?;
+    let part = fields.next()?.parse().ok()?;
+    let input = fields.next()?.to_string();
+    let answer = fields.next()?.to_string();
+
+    if fields.next().is_some() {
+        return None;
+    }
+
+    Some((
+        AnswerKey {
+            year,
+            day,
+            part,
+            input,
+        },
+        answer,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(part: Part) -> AnswerKey {
+        AnswerKey {
+            year: 2025,
+            day: 3,
+            part,
+            input: "day3.input".to_string(),
+        }
+    }
+
+    #[test]
+    fn round_trips_answers() {
+        let path = std::env::temp_dir().join(format!("aoc-answers-{}.txt", std::process::id()));
+        let _ = fs::remove_file(&path);
+
+        let mut answers = AnswerStore::load(&path).unwrap();
+        answers.record(key(Part::One), &Answer::Number(357));
+        answers.record(key(Part::Two), &Answer::from("ABC"));
+        answers.save().unwrap();
+
+        let loaded = AnswerStore::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(loaded.entries, answers.entries);
+    }
+
+    #[test]
+    fn rejects_malformed_lines() {
+        let path = std::env::temp_dir().join(format!("aoc-answers-bad-{}.txt", std::process::id()));
+        fs::write(&path, "# comment\n\n2025\t3\t1\tday3.input\n").unwrap();
+
+        let error = AnswerStore::load(&path).err().unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        assert!(error.to_string().ends_with(":3: malformed answer line"));
+    }
+
+    #[test]
+    fn checks_answers() {
+        let mut answers = AnswerStore {
+            path: PathBuf::new(),
+            entries: BTreeMap::new(),
+        };
+        answers.record(key(Part::One), &Answer::Number(357));
+
+        assert_eq!(
+            answers.check(&key(Part::One), &Answer::Number(357)),
+            Verdict::Correct
+        );
+        assert_eq!(
+            answers.check(&key(Part::One), &Answer::Number(358)),
+            Verdict::Wrong {
+                expected: "357".to_string()
+            }
+        );
+        assert_eq!(
+            answers.check(&key(Part::Two), &Answer::Number(357)),
+            Verdict::Unknown
+        );
+    }
+}
@@ -0,0 +1,324 @@
+use std::ops::RangeInclusive;
+use std::path::PathBuf;
+
+use crate::answers::DEFAULT_ANSWERS_FILE;
+use crate::history::DEFAULT_HISTORY_FILE;
+use crate::solution::{Answer, Part};
+
+pub const USAGE: &str = "\
+Usage: AdventOfCode [COMMAND] [OPTIONS]
+
+Runs the latest registered day when no selection is given.
+
+Commands:
+  run                 Run the selected solutions [default]
+  fetch               Download the inputs of the selected days that are not cached yet
+  new                 Create, register and add empty inputs for the module of a single new day
+  submit              Submit the answer of '--part' for a single day, solving it unless '--answer' is given
+
+Options:
+  --all               Run every registered day of every year
+  --year <YEAR>       Select a year (defaults to the latest registered year)
+  --day <DAY>         Select a day or an inclusive range of days, e.g. 3 or 1-5
+  --part <PART>       Only run part 1 or part 2
+  --input <PATH>      Read the puzzle input from PATH, or from stdin when PATH is '-'
+  --test              Use the example input instead of the real one, same as '--example test'
+  --example <NAME>    Use the named example input day<DAY>_<NAME>.input, e.g. test2
+  --answers <PATH>    Known answers file to verify results against [default: answers.txt]
+  --save-answers      Record answers that are not yet known in the answers file
+  --variants          Also run the alternative implementations of each part and compare them
+  --visualize <DIR>   Print the frames a day can draw and write them as an animated GIF into DIR
+  --format <FORMAT>   Output 'text' or one JSON object per part with 'json' [default: text]
+  --answer <ANSWER>   Answer to submit instead of the computed one
+  --history <PATH>    Submitted answers, used to refuse ruled out ones [default: history.txt]
+  -h, --help          Print this message
+
+Inputs are read from src/<YEAR>/input, relative to the current directory or the crate.
+Set AOC_INPUT_DIR, or 'input_dir = <PATH>' in aoc.conf, to read them from <PATH>/<YEAR> instead.
+'fetch' and 'submit' log in with the session cookie in AOC_SESSION and talk to https://adventofcode.com,
+or to AOC_BASE_URL / 'base_url = <URL>' in aoc.conf.";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    Default,
+    File(PathBuf),
+    Stdin,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+    Run,
+    Fetch,
+    Submit,
+    New,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    Text,
+    Json,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Options {
+    pub command: Command,
+    pub all: bool,
+    pub year: Option<u16>,
+    pub days: Option<RangeInclusive<u8>>,
+    pub part: Option<Part>,
+    pub input: InputSource,
+    /// Name of the example input to use instead of the real one.
+    pub example: Option<String>,
+    pub answers: PathBuf,
+    pub save_answers: bool,
+    pub format: OutputFormat,
+    pub variants: bool,
+    pub visualize: Option<PathBuf>,
+    pub answer: Option<Answer>,
+    pub history: PathBuf,
+    pub help: bool,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            command: Command::Run,
+            all: false,
+            year: None,
+            days: None,
+            part: None,
+            input: InputSource::Default,
+            example: None,
+            answers: PathBuf::from(DEFAULT_ANSWERS_FILE),
+            save_answers: false,
+            format: OutputFormat::Text,
+            variants: false,
+            visualize: None,
+            answer: None,
+            history: PathBuf::from(DEFAULT_HISTORY_FILE),
+            help: false,
+        }
+    }
+}
+
+pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Options, String> {
+    let mut options = Options::default();
+    let mut args = args.into_iter().peekable();
+
+    if let Some(command) = args.peek().and_then(|arg| parse_command(arg)) {
+        options.command = command;
+        args.next();
+    }
+
+    while let Some(arg) = args.next() {
+        let mut value = |flag: &str| {
+            args.next()
+                .ok_or_else(|| format!("Missing value for '{}'", flag))
+        };
+
+        match arg.as_str() {
+            "--all" => options.all = true,
+            "--year" => options.year = Some(parse_year(&value("--year")?)?),
+            "--day" => options.days = Some(parse_days(&value("--day")?)?),
+            "--part" => options.part = Some(value("--part")?.parse()?),
+            "--input" => {
+                options.input = match value("--input")?.as_str() {
+                    "-" => InputSource::Stdin,
+                    path => InputSource::File(PathBuf::from(path)),
+                }
+            }
+            "--test" => options.example = Some("test".to_string()),
+            "--example" => options.example = Some(parse_example(&value("--example")?)?),
+            "--answers" => options.answers = PathBuf::from(value("--answers")?),
+            "--save-answers" => options.save_answers = true,
+            "--format" => {
+                options.format = match value("--format")?.as_str() {
+                    "text" => OutputFormat::Text,
+                    "json" => OutputFormat::Json,
+                    format => return Err(format!("Unknown output format '{}'", format)),
+                }
+            }
+            "--variants" => options.variants = true,
+            "--visualize" => options.visualize = Some(PathBuf::from(value("--visualize")?)),
+            "--answer" => options.answer = Some(parse_answer(&value("--answer")?)),
+            "--history" => options.history = PathBuf::from(value("--history")?),
+            "-h" | "--help" => options.help = true,
+            _ => return Err(format!("Unknown argument '{}'", arg)),
+        }
+    }
+
+    if options.all && (options.year.is_some() || options.days.is_some()) {
+        return Err("'--all' cannot be combined with '--year' or '--day'".to_string());
+    }
+    if options.command == Command::Fetch && (options.all || options.days.is_none()) {
+        return Err("'fetch' needs the days to download as '--day'".to_string());
+    }
+    let single_day = options
+        .days
+        .as_ref()
+        .filter(|days| days.start() == days.end());
+    if options.command == Command::New && single_day.is_none() {
+        return Err("'new' needs a single day as '--day'".to_string());
+    }
+    if options.command == Command::Submit {
+        if single_day.is_none() {
+            return Err("'submit' needs a single day as '--day'".to_string());
+        }
+        if options.part.is_none() {
+            return Err("'submit' needs the part to submit as '--part'".to_string());
+        }
+        if options.example.is_some() {
+            return Err("'submit' cannot be combined with '--test' or '--example'".to_string());
+        }
+    } else if options.answer.is_some() {
+        return Err("'--answer' can only be used with 'submit'".to_string());
+    }
+    if options.input != InputSource::Default && options.example.is_some() {
+        return Err("'--input' cannot be combined with '--test' or '--example'".to_string());
+    }
+
+    Ok(options)
+}
+
+fn parse_command(value: &str) -> Option<Command> {
+    match value {
+        "run" => Some(Command::Run),
+        "fetch" => Some(Command::Fetch),
+        "submit" => Some(Command::Submit),
+        "new" => Some(Command::New),
+        _ => None,
+    }
+}
+
+fn parse_answer(value: &str) -> Answer {
+    value
+        .parse::<i128>()
+        .map_or_else(|_| Answer::from(value), Answer::Number)
+}
+
+fn parse_year(value: &str) -> Result<u16, String> {
+    value
+        .parse::<u16>()
+        .ok()
+        .filter(|year| *year >= 2015)
+        .ok_or_else(|| format!("Invalid year '{}'", value))
+}
+
+fn parse_example(value: &str) -> Result<String, String> {
+    if value.is_empty()
+        || !value
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+    {
+        return Err(format!("Invalid example name '{}'", value));
+    }
+
+    Ok(value.to_string())
+}
+
+fn parse_day(value: &str) -> Result<u8, String> {
+    value
+        .parse::<u8>()
+        .ok()
+        .filter(|day| (1..=25).contains(day))
+        .ok_or_else(|| format!("Invalid day '{}', expected a number from 1 to 25", value))
+}
+
+fn parse_days(value: &str) -> Result<RangeInclusive<u8>, String> {
+    let (start, end) = match value.split_once('-') {
+        Some((start, end)) => (parse_day(start)?, parse_day(end)?),
+        None => {
+            let day = parse_day(value)?;
+            (day, day)
+        }
+    };
+
+    if start > end {
+        return Err(format!("Invalid day range '{}'", value));
+    }
+
+    Ok(start..=end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_args(args: &str) -> Result<Options, String> {
+        parse(args.split_whitespace().map(String::from))
+    }
+
+    #[test]
+    fn parses_day_ranges() {
+        assert_eq!(parse_days("3"), Ok(3..=3));
+        assert_eq!(parse_days("1-5"), Ok(1..=5));
+        assert!(parse_days("5-1").is_err());
+        assert!(parse_days("0").is_err());
+        assert!(parse_days("26").is_err());
+        assert!(parse_days("1-").is_err());
+
+        let options = parse_args("--year 2025 --day 2-4 --part 2").unwrap();
+        assert_eq!(options.year, Some(2025));
+        assert_eq!(options.days, Some(2..=4));
+        assert_eq!(options.part, Some(Part::Two));
+        assert!(parse_args("--year 2014").is_err());
+    }
+
+    #[test]
+    fn rejects_conflicting_selections() {
+        assert!(parse_args("--all").unwrap().all);
+        assert!(parse_args("--all --year 2025").is_err());
+        assert!(parse_args("--all --day 3").is_err());
+        assert!(parse_args("--day").is_err());
+        assert!(parse_args("--frobnicate").is_err());
+    }
+
+    #[test]
+    fn parses_input_sources() {
+        assert_eq!(parse_args("").unwrap().input, InputSource::Default);
+        assert_eq!(parse_args("--input -").unwrap().input, InputSource::Stdin);
+        assert_eq!(
+            parse_args("--input other.txt").unwrap().input,
+            InputSource::File(PathBuf::from("other.txt"))
+        );
+        assert_eq!(
+            parse_args("--test").unwrap().example.as_deref(),
+            Some("test")
+        );
+        assert_eq!(
+            parse_args("--example test2").unwrap().example.as_deref(),
+            Some("test2")
+        );
+        assert!(parse_args("--example ../day1").is_err());
+        assert!(parse_args("--input other.txt --test").is_err());
+        assert!(parse_args("--input - --example test2").is_err());
+    }
+
+    #[test]
+    fn validates_commands() {
+        let options = parse_args("submit --day 3 --part 1 --answer 42").unwrap();
+        assert_eq!(options.command, Command::Submit);
+        assert_eq!(options.answer, Some(Answer::Number(42)));
+        assert_eq!(
+            parse_args("submit --day 3 --part 1 --answer abc")
+                .unwrap()
+                .answer,
+            Some(Answer::Text("abc".to_string()))
+        );
+        assert!(parse_args("submit --day 3-4 --part 1").is_err());
+        assert!(parse_args("submit --day 3").is_err());
+        assert!(parse_args("submit --day 3 --part 1 --test").is_err());
+        assert!(parse_args("--answer 42").is_err());
+
+        assert_eq!(parse_args("new --day 5").unwrap().command, Command::New);
+        assert!(parse_args("new").is_err());
+        assert!(parse_args("new --day 1-2").is_err());
+
+        assert_eq!(
+            parse_args("fetch --day 1-3").unwrap().command,
+            Command::Fetch
+        );
+        assert!(parse_args("fetch").is_err());
+        assert!(parse_args("fetch --all").is_err());
+    }
+}
@@ -0,0 +1,169 @@
+use crate::config;
+use crate::error::{AocError, Result};
+use crate::solution::{Answer, Part};
+
+/// Environment variable holding the `session` cookie of an adventofcode.com login.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+/// Environment variable overriding the server, `base_url` in the config file does the same.
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+const USER_AGENT: &str = "github.com/BasicallyPolaris/AdventOfCode";
+
+/// Talks to the Advent of Code site, or to whatever server `base_url` points at.
+pub struct Client {
+    base_url: String,
+    session: Option<String>,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: Option<&str>) -> Client {
+        Client {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.map(str::to_string),
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+        }
+    }
+
+    /// Client for the configured base URL, logged in with the session from `AOC_SESSION`.
+    pub fn from_env() -> Client {
+        let base_url = config::env_value(BASE_URL_VAR)
+            .or_else(|| config::file_value("base_url").map(|(_, url)| url))
+            .unwrap_or_else(|| DEFAULT_BASE_URL.to_string());
+
+        Client::new(&base_url, config::env_value(SESSION_VAR).as_deref())
+    }
+
+    pub fn input(&self, year: u16, day: u8) -> Result<String> {
+        let url = format!("{}/{}/day/{}/input", self.base_url, year, day);
+        let response = self.agent.get(&url).set("Cookie", &self.cookie()?).call();
+
+        read_body(&url, response)
+    }
+
+    /// Posts `answer` for a part and returns the page the server responded with.
+    pub fn submit(&self, year: u16, day: u8, part: Part, answer: &Answer) -> Result<String> {
+        let url = format!("{}/{}/day/{}/answer", self.base_url, year, day);
+        let response = self
+            .agent
+            .post(&url)
+            .set("Cookie", &self.cookie()?)
+            .send_form(&[
+                ("level", part.to_string().as_str()),
+                ("answer", answer.to_string().as_str()),
+            ]);
+
+        read_body(&url, response)
+    }
+
+    fn cookie(&self) -> Result<String> {
+        match &self.session {
+            Some(session) => Ok(format!("session={}", session)),
+            None => Err(AocError::request(format!(
+                "Set {} to the session cookie of your Advent of Code login",
+                SESSION_VAR
+            ))),
+        }
+    }
+}
+
+fn read_body(
+    url: &str,
+    response: std::result::Result<ureq::Response, ureq::Error>,
+) -> Result<String> {
+    match response {
+        Ok(response) => response.into_string().map_err(|e| {
+            AocError::request(format!("Failed to read the response of {}: {}", url, e))
+        }),
+        Err(ureq::Error::Status(status, _)) => Err(AocError::request(format!(
+            "{} responded with status {}",
+            url, status
+        ))),
+        Err(e) => Err(AocError::request(format!("{}: {}", url, e))),
+    }
+}
+
+/// A stand-in for the puzzle server that answers a fixed number of requests.
+#[cfg(test)]
+pub mod test_server {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::thread::{self, JoinHandle};
+
+    /// A request the server received, with the header names lowercased.
+    pub struct Request {
+        pub method: String,
+        pub path: String,
+        pub headers: Vec<(String, String)>,
+        pub body: String,
+    }
+
+    impl Request {
+        pub fn header(&self, name: &str) -> Option<&str> {
+            self.headers
+                .iter()
+                .find(|(n, _)| n == name)
+                .map(|(_, value)| value.as_str())
+        }
+    }
+
+    /// Serves one `(status, body)` response per request and returns the base URL and a handle
+    /// that yields the received requests once all responses are sent.
+    pub fn serve(responses: Vec<(u16, &'static str)>) -> (String, JoinHandle<Vec<Request>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("Failed to bind test server");
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let mut requests = Vec::new();
+            for (status, body) in responses {
+                let (stream, _) = listener.accept().expect("Failed to accept connection");
+                let mut reader = BufReader::new(stream);
+
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+                let mut parts = request_line.split_whitespace();
+                let method = parts.next().unwrap_or_default().to_string();
+                let path = parts.next().unwrap_or_default().to_string();
+
+                let mut headers = Vec::new();
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    match line.trim_end().split_once(": ") {
+                        Some((name, value)) => {
+                            headers.push((name.to_lowercase(), value.to_string()))
+                        }
+                        None => break,
+                    }
+                }
+
+                let length = headers
+                    .iter()
+                    .find(|(name, _)| name == "content-length")
+                    .map_or(0, |(_, value)| value.parse().unwrap());
+                let mut request_body = vec![0; length];
+                reader.read_exact(&mut request_body).unwrap();
+
+                write!(
+                    reader.get_mut(),
+                    "HTTP/1.1 {} Test\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+
+                requests.push(Request {
+                    method,
+                    path,
+                    headers,
+                    body: String::from_utf8(request_body).unwrap(),
+                });
+            }
+            requests
+        });
+
+        (base_url, handle)
+    }
+}
@@ -0,0 +1,65 @@
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+/// Optional `key = value` config file, looked up in the current and the crate directory.
+pub const CONFIG_FILE: &str = "aoc.conf";
+
+/// Value of the environment variable `var`, unless it is unset or empty.
+pub fn env_value(var: &str) -> Option<String> {
+    env::var(var).ok().filter(|value| !value.is_empty())
+}
+
+/// First `key` entry of a config file, together with the directory that file is in.
+pub fn file_value(key: &str) -> Option<(PathBuf, String)> {
+    search_roots().into_iter().find_map(|root| {
+        let contents = fs::read_to_string(root.join(CONFIG_FILE)).ok()?;
+        let value = parse_value(&contents, key)?.to_string();
+        Some((root, value))
+    })
+}
+
+/// Directories that relative defaults are tried in: the current one, then the crate itself.
+pub fn search_roots() -> Vec<PathBuf> {
+    let mut roots: Vec<PathBuf> = env::current_dir().into_iter().collect();
+
+    let crate_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    if !roots.contains(&crate_dir) {
+        roots.push(crate_dir);
+    }
+    roots
+}
+
+fn parse_value<'a>(contents: &'a str, key: &str) -> Option<&'a str> {
+    contents
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| line.split_once('='))
+        .find(|(k, _)| k.trim() == key)
+        .map(|(_, value)| value.trim())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_values() {
+        let contents = "\
+# input_dir = commented
+base_url=http://localhost:8080
+
+  input_dir =  ../inputs  
+input_dir = second
+";
+
+        assert_eq!(parse_value(contents, "input_dir"), Some("../inputs"));
+        assert_eq!(
+            parse_value(contents, "base_url"),
+            Some("http://localhost:8080")
+        );
+        assert_eq!(parse_value(contents, "session"), None);
+        assert_eq!(parse_value("input_dir\n", "input_dir"), None);
+    }
+}
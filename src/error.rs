@@ -0,0 +1,116 @@
+use std::fmt;
+use std::io;
+use std::path::PathBuf;
+
+#[derive(Debug)]
+pub enum AocError {
+    MissingInput {
+        path: PathBuf,
+        source: io::Error,
+    },
+    /// Writing a file, e.g. a downloaded input, failed.
+    Io {
+        path: PathBuf,
+        source: io::Error,
+    },
+    /// None of the input directories contains the file `name`.
+    InputNotFound {
+        name: String,
+        tried: Vec<PathBuf>,
+    },
+    /// A malformed input line; `line` and `column` are 1-based.
+    Parse {
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    Solver(String),
+    /// Talking to the puzzle server failed, or it could not be configured.
+    Request(String),
+    /// An answer was not submitted, as earlier submissions already rule it out or the server
+    /// still rate limits submissions.
+    Refused(String),
+}
+
+pub type Result<T> = std::result::Result<T, AocError>;
+
+impl AocError {
+    /// Parse error for the 0-based `line_index` at `column` (1-based).
+    pub fn parse(line_index: usize, column: usize, message: impl Into<String>) -> AocError {
+        AocError::Parse {
+            line: line_index + 1,
+            column,
+            message: message.into(),
+        }
+    }
+
+    /// Parse error pointing at `token`, which has to be a subslice of `line`.
+    pub fn parse_at(
+        line_index: usize,
+        line: &str,
+        token: &str,
+        message: impl Into<String>,
+    ) -> AocError {
+        AocError::parse(line_index, column_of(line, token), message)
+    }
+
+    pub fn solver(message: impl Into<String>) -> AocError {
+        AocError::Solver(message.into())
+    }
+
+    pub fn request(message: impl Into<String>) -> AocError {
+        AocError::Request(message.into())
+    }
+}
+
+/// 1-based column of `token` within `line`, for tokens sliced out of `line`.
+pub fn column_of(line: &str, token: &str) -> usize {
+    let offset = (token.as_ptr() as usize).wrapping_sub(line.as_ptr() as usize);
+
+    if offset <= line.len() {
+        line[..offset].chars().count() + 1
+    } else {
+        1
+    }
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AocError::MissingInput { path, source } => {
+                write!(f, "Failed to read input '{}': {}", path.display(), source)
+            }
+            AocError::Io { path, source } => {
+                write!(f, "Failed to write '{}': {}", path.display(), source)
+            }
+            AocError::InputNotFound { name, tried } => {
+                write!(f, "Input '{}' not found, tried:", name)?;
+                for path in tried {
+                    write!(f, "\n  {}", path.display())?;
+                }
+                Ok(())
+            }
+            AocError::Parse {
+                line,
+                column,
+                message,
+            } => write!(
+                f,
+                "Parse error at line {}, column {}: {}",
+                line, column, message
+            ),
+            AocError::Solver(message) => write!(f, "Solver failed: {}", message),
+            AocError::Request(message) => write!(f, "Request failed: {}", message),
+            AocError::Refused(reason) => write!(f, "Refusing to submit: {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for AocError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            AocError::MissingInput { source, .. } | AocError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
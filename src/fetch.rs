@@ -0,0 +1,135 @@
+use std::fs;
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
+
+use crate::client::Client;
+use crate::error::{AocError, Result};
+use crate::inputs;
+
+pub enum Fetched {
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+/// Fetches the inputs of `days`, printing one line per day, and returns whether none failed.
+pub fn fetch_days(client: &Client, year: u16, days: RangeInclusive<u8>) -> bool {
+    let mut all_fetched = true;
+
+    for day in days {
+        let name = inputs::file_name(day, None);
+        let path = inputs::resolve(year, &name).unwrap_or_else(|_| inputs::cache_path(year, &name));
+
+        match fetch_input(client, year, day, &path) {
+            Ok(Fetched::Cached(path)) => {
+                println!("📦 {} day {}: cached at {}", year, day, path.display())
+            }
+            Ok(Fetched::Downloaded(path)) => {
+                println!("📥 {} day {}: downloaded to {}", year, day, path.display())
+            }
+            Err(e) => {
+                println!("💥 {} day {}: {}", year, day, e);
+                all_fetched = false;
+            }
+        }
+    }
+
+    all_fetched
+}
+
+/// Downloads the input of a day into `path`, unless that file already exists.
+pub fn fetch_input(client: &Client, year: u16, day: u8, path: &Path) -> Result<Fetched> {
+    if path.is_file() {
+        return Ok(Fetched::Cached(path.to_path_buf()));
+    }
+
+    let contents = client.input(year, day)?;
+
+    let io_error = |source| AocError::Io {
+        path: path.to_path_buf(),
+        source,
+    };
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(io_error)?;
+    }
+    // Write to a temporary file first, so an interrupted download never looks cached
+    let partial = path.with_extension("part");
+    fs::write(&partial, contents).map_err(io_error)?;
+    fs::rename(&partial, path).map_err(io_error)?;
+
+    Ok(Fetched::Downloaded(path.to_path_buf()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::test_server;
+
+    fn temp_input(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-fetch-{}-{}", std::process::id(), name));
+        let _ = fs::remove_dir_all(&dir);
+        dir.join("day5.input")
+    }
+
+    fn remove_temp_input(path: &Path) {
+        let _ = fs::remove_dir_all(path.parent().unwrap());
+    }
+
+    #[test]
+    fn downloads_missing_input() {
+        let (base_url, server) = test_server::serve(vec![(200, "3-5\n\n1\n")]);
+        let path = temp_input("download");
+
+        let client = Client::new(&base_url, Some("secret"));
+        let fetched = fetch_input(&client, 2025, 5, &path).unwrap();
+
+        let requests = server.join().unwrap();
+        let contents = fs::read_to_string(&path);
+        remove_temp_input(&path);
+        assert!(matches!(fetched, Fetched::Downloaded(_)));
+        assert_eq!(contents.unwrap(), "3-5\n\n1\n");
+        assert_eq!(requests[0].path, "/2025/day/5/input");
+        assert_eq!(requests[0].header("cookie"), Some("session=secret"));
+    }
+
+    #[test]
+    fn never_downloads_cached_input() {
+        let path = temp_input("cached");
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, "cached").unwrap();
+
+        // Nothing listens on the discard port, any request would fail
+        let client = Client::new("http://127.0.0.1:9", Some("secret"));
+        let fetched = fetch_input(&client, 2025, 5, &path).unwrap();
+
+        let contents = fs::read_to_string(&path);
+        remove_temp_input(&path);
+        assert!(matches!(fetched, Fetched::Cached(_)));
+        assert_eq!(contents.unwrap(), "cached");
+    }
+
+    #[test]
+    fn failed_download_leaves_no_file() {
+        let (base_url, server) = test_server::serve(vec![(404, "Not found")]);
+        let path = temp_input("failed");
+
+        let client = Client::new(&base_url, Some("secret"));
+        let result = fetch_input(&client, 2025, 5, &path);
+
+        server.join().unwrap();
+        let exists = path.exists();
+        remove_temp_input(&path);
+        assert!(matches!(result, Err(AocError::Request(_))));
+        assert!(!exists);
+    }
+
+    #[test]
+    fn requires_session() {
+        let path = temp_input("session");
+
+        let client = Client::new("http://127.0.0.1:9", None);
+
+        let result = fetch_input(&client, 2025, 5, &path);
+        remove_temp_input(&path);
+        assert!(matches!(result, Err(AocError::Request(_))));
+    }
+}
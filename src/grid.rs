@@ -0,0 +1,277 @@
+use std::fmt;
+use std::iter::StepBy;
+use std::ops::{Index, IndexMut};
+use std::slice::{ChunksExact, Iter};
+
+use crate::error::{AocError, Result};
+
+/// A position in a grid as `(x, y)`, with `(0, 0)` in the top left corner.
+pub type Pos = (usize, usize);
+
+const NEIGHBOURS_4: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+const NEIGHBOURS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// A rectangular grid of cells stored row after row in a single `Vec`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (x, y): Pos) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos).then(|| &self.cells[self.offset(pos)])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if !self.contains(pos) {
+            return None;
+        }
+        let offset = self.offset(pos);
+        Some(&mut self.cells[offset])
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// The up to 4 positions orthogonally next to `pos`.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        self.offsets(pos, &NEIGHBOURS_4)
+    }
+
+    /// The up to 8 positions orthogonally or diagonally next to `pos`.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        self.offsets(pos, &NEIGHBOURS_8)
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> ChunksExact<'_, T> {
+        // `chunks_exact` panics on 0, an empty row still has to yield nothing
+        self.cells.chunks_exact(self.width.max(1))
+    }
+
+    /// The cells of column `x`, top to bottom.
+    pub fn column(&self, x: usize) -> StepBy<Iter<'_, T>> {
+        assert!(x < self.width, "Column {} out of bounds", x);
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl DoubleEndedIterator<Item = StepBy<Iter<'_, T>>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// The grid mirrored along its diagonal, so columns become rows.
+    pub fn transposed(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            width: self.height,
+            height: self.width,
+            cells: self.columns().flatten().cloned().collect(),
+        }
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    fn offset(&self, (x, y): Pos) -> usize {
+        y * self.width + x
+    }
+
+    fn offsets<'a>(
+        &'a self,
+        (x, y): Pos,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = Pos> + 'a {
+        offsets.iter().filter_map(move |&(dx, dy)| {
+            let pos = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+            self.contains(pos).then_some(pos)
+        })
+    }
+}
+
+impl Grid<char> {
+    /// Reads one row per line, every line has to be as long as the first.
+    pub fn parse(input: &str) -> Result<Grid<char>> {
+        let mut lines = input.lines().enumerate();
+        let Some((_, first)) = lines.next() else {
+            return Ok(Grid::new(0, 0, ' '));
+        };
+
+        let mut cells: Vec<char> = first.chars().collect();
+        let width = cells.len();
+        let mut height = 1;
+
+        for (i, line) in lines {
+            let length = line.chars().count();
+            if length != width {
+                return Err(AocError::parse(
+                    i,
+                    width.min(length) + 1,
+                    format!("Expected {} columns, found {}", width, length),
+                ));
+            }
+            cells.extend(line.chars());
+            height += 1;
+        }
+
+        Ok(Grid {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    /// Reads one row per line, padding lines shorter than the longest one with `fill`, e.g.
+    /// for inputs whose trailing spaces might have been stripped.
+    pub fn parse_padded(input: &str, fill: char) -> Grid<char> {
+        let width = input.lines().map(|l| l.chars().count()).max().unwrap_or(0);
+        let height = input.lines().count();
+
+        let mut cells = Vec::with_capacity(width * height);
+        for line in input.lines() {
+            let start = cells.len();
+            cells.extend(line.chars());
+            cells.resize(start + width, fill);
+        }
+
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        assert!(self.contains(pos), "{:?} out of bounds", pos);
+        &self.cells[self.offset(pos)]
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        assert!(self.contains(pos), "{:?} out of bounds", pos);
+        let offset = self.offset(pos);
+        &mut self.cells[offset]
+    }
+}
+
+/// Writes the grid back as text, one line per row.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEXT: &str = "ab.\n.cd\n";
+
+    #[test]
+    fn parses_and_displays() {
+        let grid = Grid::parse(TEXT).unwrap();
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(1, 1)], 'c');
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.to_string(), TEXT);
+        assert!(matches!(
+            Grid::parse("abc\nab\n"),
+            Err(AocError::Parse {
+                line: 2,
+                column: 3,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn pads_short_lines() {
+        let grid = Grid::parse_padded("ab\na\n", ' ');
+
+        assert_eq!(grid.to_string(), "ab\na \n");
+    }
+
+    #[test]
+    fn neighbours_stay_in_bounds() {
+        let grid = Grid::new(3, 3, 0);
+
+        assert_eq!(
+            grid.neighbours4((0, 0)).collect::<Vec<_>>(),
+            vec![(1, 0), (0, 1)]
+        );
+        assert_eq!(grid.neighbours8((0, 2)).count(), 3);
+        assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+    }
+
+    #[test]
+    fn columns_and_transposition() {
+        let grid = Grid::parse(TEXT).unwrap();
+
+        assert_eq!(grid.column(2).collect::<String>(), ".d");
+        assert_eq!(grid.row(1), ['.', 'c', 'd']);
+        assert_eq!(grid.transposed().to_string(), "a.\nbc\n.d\n");
+    }
+}
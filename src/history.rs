@@ -0,0 +1,208 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::solution::{Answer, Part};
+
+pub const DEFAULT_HISTORY_FILE: &str = "history.txt";
+
+/// Prefix of the line holding the end of the last rate limit.
+const NOT_BEFORE: &str = "not-before\t";
+
+/// What the puzzle server said about a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+}
+
+impl Outcome {
+    fn as_str(self) -> &'static str {
+        match self {
+            Outcome::Correct => "correct",
+            Outcome::TooHigh => "too-high",
+            Outcome::TooLow => "too-low",
+            Outcome::Wrong => "wrong",
+        }
+    }
+
+    fn parse(value: &str) -> Option<Outcome> {
+        match value {
+            "correct" => Some(Outcome::Correct),
+            "too-high" => Some(Outcome::TooHigh),
+            "too-low" => Some(Outcome::TooLow),
+            "wrong" => Some(Outcome::Wrong),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submission {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    pub answer: String,
+    pub outcome: Outcome,
+}
+
+/// Every answer submitted so far, stored as one tab separated `year day part answer outcome`
+/// line each, and a `not-before <unix seconds>` line while the server rate limits submissions.
+pub struct History {
+    path: PathBuf,
+    submissions: Vec<Submission>,
+    not_before: Option<SystemTime>,
+}
+
+impl History {
+    pub fn load(path: &Path) -> io::Result<History> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e),
+        };
+
+        let mut submissions = Vec::new();
+        let mut not_before = None;
+        for (i, line) in contents.lines().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let malformed = || {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("{}:{}: malformed submission line", path.display(), i + 1),
+                )
+            };
+            match line.strip_prefix(NOT_BEFORE) {
+                Some(seconds) => {
+                    let seconds = seconds.parse().map_err(|_| malformed())?;
+                    not_before = Some(UNIX_EPOCH + Duration::from_secs(seconds));
+                }
+                None => submissions.push(parse_line(line).ok_or_else(malformed)?),
+            }
+        }
+
+        Ok(History {
+            path: path.to_path_buf(),
+            submissions,
+            not_before,
+        })
+    }
+
+    pub fn record(&mut self, submission: Submission) {
+        self.submissions.push(submission);
+    }
+
+    /// Remembers that the server takes no submissions before `time`.
+    pub fn wait_until(&mut self, time: SystemTime) {
+        self.not_before = self.not_before.max(Some(time));
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        let mut contents = String::from("# year\tday\tpart\tanswer\toutcome\n");
+        if let Some(not_before) = self.not_before {
+            // Rounded up, so the wait never ends early
+            let seconds = not_before.duration_since(UNIX_EPOCH).map_or(0, |since| {
+                since.as_secs() + u64::from(since.subsec_nanos() > 0)
+            });
+            contents.push_str(&format!("{}{}\n", NOT_BEFORE, seconds));
+        }
+        for submission in &self.submissions {
+            contents.push_str(&format!(
+                "{}\t{}\t{}\t{}\t{}\n",
+                submission.year,
+                submission.day,
+                submission.part,
+                submission.answer,
+                submission.outcome.as_str()
+            ));
+        }
+
+        fs::write(&self.path, contents)
+    }
+
+    /// Why `answer` is not worth submitting at `now`, judging by the earlier submissions for the
+    /// part and the last rate limit.
+    pub fn refusal(
+        &self,
+        year: u16,
+        day: u8,
+        part: Part,
+        answer: &Answer,
+        now: SystemTime,
+    ) -> Option<String> {
+        if let Some(left) = self
+            .not_before
+            .and_then(|not_before| not_before.duration_since(now).ok())
+            .filter(|left| !left.is_zero())
+        {
+            return Some(format!(
+                "rate limited, try again in {}m {}s",
+                left.as_secs() / 60,
+                left.as_secs() % 60
+            ));
+        }
+
+        let answer_string = answer.to_string();
+
+        for submission in &self.submissions {
+            if (submission.year, submission.day, submission.part) != (year, day, part) {
+                continue;
+            }
+
+            if submission.outcome == Outcome::Correct {
+                return Some(format!("already solved with {}", submission.answer));
+            }
+            if submission.answer == answer_string {
+                return Some(format!(
+                    "{} was already submitted and is wrong",
+                    answer_string
+                ));
+            }
+
+            // Bounds only compare numbers, anything else can only be ruled out verbatim
+            let (Answer::Number(value), Ok(bound)) = (answer, submission.answer.parse::<i128>())
+            else {
+                continue;
+            };
+            match submission.outcome {
+                Outcome::TooHigh if *value >= bound => {
+                    return Some(format!("{} is too high, {} already was", value, bound));
+                }
+                Outcome::TooLow if *value <= bound => {
+                    return Some(format!("{} is too low, {} already was", value, bound));
+                }
+                _ => {}
+            }
+        }
+
+        None
+    }
+}
+
+fn parse_line(line: &str) -> Option<Submission> {
+    let mut fields = line.split('\t');
+
+    let year = fields.next()?.parse().ok()?;
+    let day = fields.next()?.parse().ok()?;
+    let part = fields.next()?.parse().ok()?;
+    let answer = fields.next()?.to_string();
+    let outcome = Outcome::parse(fields.next()?)?;
+
+    if fields.next().is_some() {
+        return None;
+    }
+
+    Some(Submission {
+        year,
+        day,
+        part,
+        answer,
+        outcome,
+    })
+}
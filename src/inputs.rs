@@ -0,0 +1,119 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::config;
+use crate::error::{AocError, Result};
+
+/// Environment variable pointing at a directory with one `<year>/` folder of inputs per year.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// File name of a day's input, `day7.input` or `day7_<example>.input` for a named example.
+pub fn file_name(day: u8, example: Option<&str>) -> String {
+    match example {
+        Some(example) => format!("day{}_{}.input", day, example),
+        None => format!("day{}.input", day),
+    }
+}
+
+/// Finds `name` in the input directories of `year`, listing every tried path when it is missing.
+pub fn resolve(year: u16, name: &str) -> Result<PathBuf> {
+    let tried: Vec<PathBuf> = year_dirs(year)
+        .into_iter()
+        .map(|dir| dir.join(name))
+        .collect();
+
+    match tried.iter().find(|path| path.is_file()) {
+        Some(path) => Ok(path.clone()),
+        None => Err(AocError::InputNotFound {
+            name: name.to_string(),
+            tried,
+        }),
+    }
+}
+
+pub fn read_file(path: &Path) -> Result<String> {
+    fs::read_to_string(path).map_err(|source| AocError::MissingInput {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+/// Where a downloaded input is stored: in the first input directory of `year` that exists, or
+/// in the first one that is searched otherwise.
+pub fn cache_path(year: u16, name: &str) -> PathBuf {
+    let dirs = year_dirs(year);
+    let dir = dirs.iter().find(|dir| dir.is_dir()).unwrap_or(&dirs[0]);
+
+    dir.join(name)
+}
+
+/// Directories searched for the inputs of `year`, in order.
+///
+/// An `AOC_INPUT_DIR` or an `input_dir` from the config file replaces the defaults, which are
+/// `src/<year>/input` relative to the current directory and to the crate itself.
+fn year_dirs(year: u16) -> Vec<PathBuf> {
+    if let Some(dir) = input_dir_override() {
+        return vec![dir.join(year.to_string())];
+    }
+
+    config::search_roots()
+        .into_iter()
+        .map(|root| root.join("src").join(year.to_string()).join("input"))
+        .collect()
+}
+
+fn input_dir_override() -> Option<PathBuf> {
+    if let Some(dir) = config::env_value(INPUT_DIR_VAR) {
+        return Some(PathBuf::from(dir));
+    }
+
+    // Relative directories are taken relative to the config file
+    config::file_value("input_dir").map(|(root, dir)| root.join(dir))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The only test touching AOC_INPUT_DIR, so tests running in parallel never see it change
+    #[test]
+    fn resolves_inputs() {
+        std::env::remove_var(INPUT_DIR_VAR);
+        match resolve(2025, "day99.input") {
+            Err(AocError::InputNotFound { name, tried }) => {
+                assert_eq!(name, "day99.input");
+                assert_eq!(tried.len(), config::search_roots().len());
+                assert!(tried
+                    .iter()
+                    .all(|path| path.ends_with("src/2025/input/day99.input")));
+            }
+            other => panic!("Expected a missing input, got {:?}", other),
+        }
+
+        let dir = std::env::temp_dir().join(format!("aoc-inputs-{}", std::process::id()));
+        fs::create_dir_all(dir.join("2025")).unwrap();
+        fs::write(dir.join("2025").join("day1.input"), "R10\n").unwrap();
+        std::env::set_var(INPUT_DIR_VAR, &dir);
+
+        let found = resolve(2025, "day1.input");
+        let missing = resolve(2025, "day2.input");
+        let cached = cache_path(2024, "day2.input");
+        std::env::remove_var(INPUT_DIR_VAR);
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(found.unwrap(), dir.join("2025").join("day1.input"));
+        match missing {
+            Err(AocError::InputNotFound { tried, .. }) => {
+                assert_eq!(tried, [dir.join("2025").join("day2.input")]);
+            }
+            other => panic!("Expected a missing input, got {:?}", other),
+        }
+        assert_eq!(cached, dir.join("2024").join("day2.input"));
+    }
+
+    #[test]
+    fn names_inputs() {
+        assert_eq!(file_name(7, None), "day7.input");
+        assert_eq!(file_name(7, Some("test2")), "day7_test2.input");
+    }
+}
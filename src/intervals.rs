@@ -0,0 +1,163 @@
+/// A set of integers stored as sorted, disjoint inclusive ranges `(start, end)`.
+///
+/// Touching or overlapping ranges are merged on insert, so every value is covered by at most
+/// one range and membership is a binary search.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IntervalSet {
+    ranges: Vec<(u64, u64)>,
+}
+
+impl IntervalSet {
+    pub fn new() -> IntervalSet {
+        IntervalSet::default()
+    }
+
+    /// Adds every value of `start..=end`, an empty range with `start > end` adds nothing.
+    pub fn insert(&mut self, start: u64, end: u64) {
+        if start > end {
+            return;
+        }
+
+        // Ranges ending right before `start` or starting right after `end` merge as well
+        let first = self
+            .ranges
+            .partition_point(|&(_, e)| e.saturating_add(1) < start);
+        let last = self
+            .ranges
+            .partition_point(|&(s, _)| s <= end.saturating_add(1));
+
+        let merged = match self.ranges[first..last] {
+            [] => (start, end),
+            [(s, _), .., (_, e)] | [(s, e)] => (s.min(start), e.max(end)),
+        };
+        self.ranges.splice(first..last, [merged]);
+    }
+
+    pub fn contains(&self, value: u64) -> bool {
+        let i = self.ranges.partition_point(|&(_, end)| end < value);
+        self.ranges.get(i).is_some_and(|&(start, _)| start <= value)
+    }
+
+    /// Number of values in the set, a `u128` as all of `0..=u64::MAX` holds one more than fits.
+    pub fn len(&self) -> u128 {
+        self.ranges
+            .iter()
+            .map(|&(start, end)| (end - start) as u128 + 1)
+            .sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// The merged ranges in ascending order.
+    pub fn ranges(&self) -> &[(u64, u64)] {
+        &self.ranges
+    }
+
+    pub fn union(&self, other: &IntervalSet) -> IntervalSet {
+        let mut union = self.clone();
+        for &(start, end) in &other.ranges {
+            union.insert(start, end);
+        }
+        union
+    }
+
+    pub fn intersection(&self, other: &IntervalSet) -> IntervalSet {
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+
+        while let (Some(&(a_start, a_end)), Some(&(b_start, b_end))) =
+            (self.ranges.get(i), other.ranges.get(j))
+        {
+            let (start, end) = (a_start.max(b_start), a_end.min(b_end));
+            if start <= end {
+                ranges.push((start, end));
+            }
+            // The range ending first can't overlap anything further along the other set
+            if a_end < b_end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        IntervalSet { ranges }
+    }
+
+    /// The values of `self` which are not in `other`.
+    pub fn difference(&self, other: &IntervalSet) -> IntervalSet {
+        let mut ranges = Vec::new();
+        let mut j = 0;
+
+        for &(start, end) in &self.ranges {
+            let mut start = Some(start);
+            while let (Some(s), Some(&(b_start, b_end))) = (start, other.ranges.get(j)) {
+                if b_end < s {
+                    j += 1;
+                    continue;
+                }
+                if b_start > end {
+                    break;
+                }
+                if b_start > s {
+                    ranges.push((s, b_start - 1));
+                }
+                // Stay on this range of `other`, it might cut into the next range of `self`
+                start = b_end.checked_add(1).filter(|&next| next <= end);
+                if start.is_some() {
+                    j += 1;
+                }
+            }
+            if let Some(s) = start {
+                ranges.push((s, end));
+            }
+        }
+
+        IntervalSet { ranges }
+    }
+}
+
+impl FromIterator<(u64, u64)> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = (u64, u64)>>(ranges: I) -> IntervalSet {
+        let mut set = IntervalSet::new();
+        for (start, end) in ranges {
+            set.insert(start, end);
+        }
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(ranges: &[(u64, u64)]) -> IntervalSet {
+        ranges.iter().copied().collect()
+    }
+
+    #[test]
+    fn merges_on_insert() {
+        let ranges = set(&[(3, 5), (10, 14), (16, 20), (12, 18), (6, 6)]);
+
+        assert_eq!(ranges.ranges(), [(3, 6), (10, 20)]);
+        assert_eq!(ranges.len(), 15);
+        assert!(ranges.contains(6));
+        assert!(!ranges.contains(8));
+        assert!(!ranges.contains(21));
+        assert!(set(&[(0, u64::MAX)]).contains(u64::MAX));
+        assert_eq!(set(&[(0, u64::MAX)]).len(), 1 << 64);
+    }
+
+    #[test]
+    fn set_operations() {
+        let a = set(&[(1, 10), (20, 30)]);
+        let b = set(&[(5, 22), (28, 40)]);
+
+        assert_eq!(a.union(&b).ranges(), [(1, 40)]);
+        assert_eq!(a.intersection(&b).ranges(), [(5, 10), (20, 22), (28, 30)]);
+        assert_eq!(a.difference(&b).ranges(), [(1, 4), (23, 27)]);
+        assert_eq!(b.difference(&a).ranges(), [(11, 19), (31, 40)]);
+        assert!(a.difference(&a).is_empty());
+    }
+}
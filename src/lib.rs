@@ -0,0 +1,29 @@
+//! Advent of Code solutions and the tooling to run, fetch, submit and scaffold them.
+//!
+//! Every day implements [`solution::Solution`] and is listed in [`registry::DAYS`], so
+//! benches, tests and other tools can call any day's parts directly. Helpers shared between
+//! days, like [`grid::Grid`] and [`parsing`], live next to the tooling.
+
+pub mod answers;
+pub mod cli;
+pub mod client;
+pub mod config;
+pub mod error;
+pub mod fetch;
+pub mod grid;
+pub mod history;
+pub mod inputs;
+pub mod intervals;
+pub mod parsing;
+pub mod registry;
+pub mod runner;
+pub mod scaffold;
+pub mod solution;
+pub mod spatial;
+pub mod submit;
+pub mod union_find;
+pub mod visual;
+#[path = "2024/mod.rs"]
+pub mod y2024;
+#[path = "2025/mod.rs"]
+pub mod y2025;
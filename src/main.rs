@@ -1,63 +1,264 @@
 use std::env;
+use std::process;
+use std::time::SystemTime;
 
-#[path = "2024/mod.rs"]
-mod y2024;
-#[path = "2025/mod.rs"]
-mod y2025;
+use advent_of_code::answers::{self, AnswerStore, Verdict};
+use advent_of_code::cli::{self, Command, InputSource, Options, OutputFormat};
+use advent_of_code::client::Client;
+use advent_of_code::error::AocError;
+use advent_of_code::history::{History, Outcome};
+use advent_of_code::registry::{self, Day};
+use advent_of_code::runner::{self, DayReport};
+use advent_of_code::solution::Answer;
+use advent_of_code::submit::{self, Response};
+use advent_of_code::{fetch, scaffold};
 
 fn main() {
-    let args: Vec<String> = env::args().collect();
-    let mut has_flags = false;
+    let options = cli::parse(env::args().skip(1)).unwrap_or_else(|e| exit_with_usage(&e));
 
-    if args.iter().any(|arg| arg == "--all") {
-        run_all();
-        has_flags = true;
+    if options.help {
+        println!("{}", cli::USAGE);
+        return;
+    }
+
+    match options.command {
+        Command::Run => run(&options),
+        Command::Fetch => fetch(&options),
+        Command::Submit => submit(&options),
+        Command::New => new_day(&options),
+    }
+}
+
+fn run(options: &Options) {
+    let days: Vec<&Day> = if options.all {
+        registry::DAYS.iter().collect()
+    } else if options.year.is_none() && options.days.is_none() {
+        registry::latest().into_iter().collect()
     } else {
-        if args.iter().any(|arg| arg == "--all-2024") {
-            run_all_2024();
-            has_flags = true;
+        registry::select(options.year, options.days.clone())
+    };
+
+    if days.is_empty() {
+        exit_with_usage("No registered solution matches the selection");
+    }
+    if days.len() > 1 && options.input != InputSource::Default {
+        exit_with_usage("'--input' can only be used when a single day is selected");
+    }
+
+    let mut answers = AnswerStore::load(&options.answers).unwrap_or_else(|e| {
+        eprintln!("Failed to load answers: {}", e);
+        process::exit(1);
+    });
+
+    let reports = run_days(&days, options, &answers);
+
+    if options.save_answers {
+        for report in &reports {
+            for part in &report.parts {
+                if let (Ok(answer), Verdict::Unknown) = (&part.answer, &part.verdict) {
+                    answers.record(report.key(part.part), answer);
+                }
+            }
         }
-        if args.iter().any(|arg| arg == "--all-2025") {
-            run_all_2025();
-            has_flags = true;
+        answers.save().unwrap_or_else(|e| {
+            eprintln!("Failed to save answers: {}", e);
+            process::exit(1);
+        });
+    }
+
+    let all_correct = match options.format {
+        OutputFormat::Text => {
+            runner::print_timings(&reports);
+            runner::print_summary(&reports)
+        }
+        OutputFormat::Json => !reports.iter().any(DayReport::has_failures),
+    };
+    if !all_correct {
+        process::exit(1);
+    }
+}
+
+fn run_days(days: &[&Day], options: &Options, answers: &AnswerStore) -> Vec<DayReport> {
+    let mut current_year = None;
+    let mut reports = Vec::new();
+
+    for day in days {
+        let report = (day.run)(options, answers);
+        if options.format == OutputFormat::Json {
+            runner::print_json(&report);
+            reports.push(report);
+            continue;
         }
+
+        if days.len() > 1 && current_year != Some(day.year) {
+            if current_year.is_some() {
+                println!();
+            }
+            println!("🎄 Running Advent of Code {} 🎁", day.year);
+            println!("--------------------------------");
+            current_year = Some(day.year);
+        }
+
+        runner::print_day(&report, options.example.is_some());
+        reports.push(report);
     }
 
-    if !has_flags {
-        run_today();
+    reports
+}
+
+fn fetch(options: &Options) {
+    let Some(year) = options.year.or(registry::latest().map(|day| day.year)) else {
+        exit_with_usage("No year to fetch inputs for, pass '--year'");
+    };
+    let days = options
+        .days
+        .clone()
+        .expect("'fetch' is validated to have days");
+
+    if !fetch::fetch_days(&Client::from_env(), year, days) {
+        process::exit(1);
     }
 }
 
-fn run_all() {
-    run_all_2024();
-    println!();
-    run_all_2025();
+fn submit(options: &Options) {
+    let Some(year) = options.year.or(registry::latest().map(|day| day.year)) else {
+        exit_with_usage("No year to submit for, pass '--year'");
+    };
+    let day = *options
+        .days
+        .as_ref()
+        .expect("'submit' is validated to have a day")
+        .start();
+    let part = options.part.expect("'submit' is validated to have a part");
+
+    let mut answers = AnswerStore::load(&options.answers).unwrap_or_else(|e| {
+        eprintln!("Failed to load answers: {}", e);
+        process::exit(1);
+    });
+    let mut history = History::load(&options.history).unwrap_or_else(|e| {
+        eprintln!("Failed to load the submission history: {}", e);
+        process::exit(1);
+    });
+
+    let label = format!("{} day {} part {}", year, day, part);
+    let answer = match &options.answer {
+        Some(answer) => Ok(answer.clone()),
+        None => solve(options, &answers, year, day),
+    };
+    let response = answer.and_then(|answer| {
+        submit::submit(
+            &Client::from_env(),
+            &mut history,
+            year,
+            day,
+            part,
+            &answer,
+            SystemTime::now(),
+        )
+        .map(|response| (answer, response))
+    });
+
+    history.save().unwrap_or_else(|e| {
+        eprintln!("Failed to save the submission history: {}", e);
+        process::exit(1);
+    });
+
+    let solved = match response {
+        Ok((answer, Response::Verdict(Outcome::Correct))) => {
+            println!("⭐ {}: {} is correct", label, answer);
+
+            let key = answers::AnswerKey {
+                year,
+                day,
+                part,
+                input: runner::input_name(options, day),
+            };
+            answers.record(key, &answer);
+            answers.save().unwrap_or_else(|e| {
+                eprintln!("Failed to save answers: {}", e);
+                process::exit(1);
+            });
+            true
+        }
+        Ok((answer, Response::Verdict(outcome))) => {
+            let verdict = match outcome {
+                Outcome::TooHigh => "too high",
+                Outcome::TooLow => "too low",
+                _ => "wrong",
+            };
+            println!("❌ {}: {} is {}", label, answer, verdict);
+            false
+        }
+        Ok((_, Response::Wait(wait))) => {
+            println!(
+                "⏳ {}: rate limited, try again in {}m {}s",
+                label,
+                wait.as_secs() / 60,
+                wait.as_secs() % 60
+            );
+            false
+        }
+        Ok((_, Response::AlreadySolved)) => {
+            println!("✅ {}: already solved", label);
+            true
+        }
+        Err(e) => {
+            println!("💥 {}: {}", label, e);
+            false
+        }
+    };
+
+    if !solved {
+        process::exit(1);
+    }
 }
 
-fn run_all_2024() {
-    println!("🎄 Running Advent of Code 2024 🎁");
-    println!("--------------------------------");
-    y2024::day1::run();
-    y2024::day2::run();
-    y2024::day3::run();
+fn new_day(options: &Options) {
+    let Some(year) = options.year.or(registry::latest().map(|day| day.year)) else {
+        exit_with_usage("No year to create the day in, pass '--year'");
+    };
+    let day = *options
+        .days
+        .as_ref()
+        .expect("'new' is validated to have a day")
+        .start();
+
+    match scaffold::new_day(year, day) {
+        Ok(created) => {
+            for path in created {
+                println!("📝 Created {}", path.display());
+            }
+            println!("🎄 Registered {} day {}, rebuild to run it", year, day);
+        }
+        Err(e) => {
+            eprintln!("💥 {} day {}: {}", year, day, e);
+            process::exit(1);
+        }
+    }
 }
 
-fn run_all_2025() {
-    println!("🎄 Running Advent of Code 2025 🎁");
-    println!("--------------------------------");
-    y2025::day1::run();
-    y2025::day2::run();
-    y2025::day3::run();
-    y2025::day4::run();
-    y2025::day5::run();
-    y2025::day6::run();
-    y2025::day7::run();
-    y2025::day8::run();
-    y2025::day9::run();
-    y2025::day10::run();
-    y2025::day11::run();
+/// Solves the selected part of a day on its real input.
+fn solve(options: &Options, answers: &AnswerStore, year: u16, day: u8) -> Result<Answer, AocError> {
+    let Some(solution) = registry::select(Some(year), Some(day..=day)).pop() else {
+        return Err(AocError::solver(format!(
+            "No solution registered for {} day {}",
+            year, day
+        )));
+    };
+
+    let report = (solution.run)(options, answers);
+    if let Some(e) = report.error {
+        return Err(e);
+    }
+    report
+        .parts
+        .into_iter()
+        .next()
+        .map(|part| part.answer)
+        .expect("The selected part always runs")
 }
 
-fn run_today() {
-    y2025::day11::run();
+fn exit_with_usage(message: &str) -> ! {
+    eprintln!("Error: {}\n\n{}", message, cli::USAGE);
+    process::exit(2);
 }
@@ -0,0 +1,190 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::error::{AocError, Result};
+
+/// One line of an input. Errors about tokens sliced out of `text` point at their column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line<'a> {
+    /// 0-based, like the indices of `str::lines().enumerate()`.
+    pub index: usize,
+    pub text: &'a str,
+}
+
+/// The lines of `input`, numbered.
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, text)| Line { index, text })
+}
+
+/// The blocks of lines separated by blank lines, keeping each line's position in `input`.
+pub fn sections(input: &str) -> Vec<Vec<Line<'_>>> {
+    let mut sections = vec![Vec::new()];
+    for line in lines(input) {
+        if line.text.trim().is_empty() {
+            sections.push(Vec::new());
+        } else if let Some(section) = sections.last_mut() {
+            section.push(line);
+        }
+    }
+    sections.retain(|section| !section.is_empty());
+
+    sections
+}
+
+impl<'a> Line<'a> {
+    /// Error pointing at `token`, which has to be a subslice of the line.
+    pub fn error(&self, token: &str, message: impl Into<String>) -> AocError {
+        AocError::parse_at(self.index, self.text, token, message)
+    }
+
+    /// Parses `token`, ignoring surrounding whitespace.
+    pub fn number<T>(&self, token: &str) -> Result<T>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let token = token.trim();
+        token
+            .parse()
+            .map_err(|e: T::Err| self.error(token, format!("'{}': {}", token, e)))
+    }
+
+    /// Parses every value of a `separator` separated list like `1,2,3`.
+    pub fn numbers<T>(&self, token: &str, separator: char) -> Result<Vec<T>>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        token.split(separator).map(|n| self.number(n)).collect()
+    }
+
+    /// Parses a list of exactly `N` values, e.g. the coordinates `x,y,z`.
+    pub fn tuple<T, const N: usize>(&self, token: &str, separator: char) -> Result<[T; N]>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let values: Vec<T> = self.numbers(token, separator)?;
+        let count = values.len();
+
+        values.try_into().map_err(|_| {
+            self.error(
+                token,
+                format!(
+                    "Expected {} values separated by '{}', found {}",
+                    N, separator, count
+                ),
+            )
+        })
+    }
+
+    /// Parses an inclusive range written as `start-end`.
+    pub fn range<T>(&self, token: &str) -> Result<(T, T)>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let (start, end) = self.split_once(token, '-')?;
+        Ok((self.number(start)?, self.number(end)?))
+    }
+
+    pub fn split_once(&self, token: &'a str, separator: char) -> Result<(&'a str, &'a str)> {
+        token
+            .split_once(separator)
+            .ok_or_else(|| self.error(token, format!("Missing '{}'", separator)))
+    }
+
+    /// The contents of a token wrapped in `open` and `close`, like `[.##.]`.
+    pub fn bracketed(&self, token: &'a str, open: char, close: char) -> Result<&'a str> {
+        token
+            .strip_prefix(open)
+            .and_then(|inner| inner.strip_suffix(close))
+            .ok_or_else(|| {
+                self.error(
+                    token,
+                    format!("Expected a group wrapped in '{}' and '{}'", open, close),
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line(text: &str) -> Line<'_> {
+        Line { index: 2, text }
+    }
+
+    #[test]
+    fn parses_typed_values() {
+        let coordinates = line("162,817, 812");
+        assert_eq!(
+            coordinates.tuple::<u32, 3>(coordinates.text, ',').unwrap(),
+            [162, 817, 812]
+        );
+
+        let ranges = line("11-22,95-115");
+        assert_eq!(
+            ranges
+                .text
+                .split(',')
+                .map(|r| ranges.range::<u64>(r))
+                .collect::<Result<Vec<_>>>()
+                .unwrap(),
+            vec![(11, 22), (95, 115)]
+        );
+
+        let machine = line("[.##.] (3) (1,3) {3,5}");
+        let tokens: Vec<&str> = machine.text.split(' ').collect();
+        assert_eq!(machine.bracketed(tokens[0], '[', ']').unwrap(), ".##.");
+        assert_eq!(
+            machine
+                .numbers::<u32>(machine.bracketed(tokens[3], '{', '}').unwrap(), ',')
+                .unwrap(),
+            vec![3, 5]
+        );
+    }
+
+    #[test]
+    fn errors_point_at_the_token() {
+        let coordinates = line("1,x,3");
+        assert!(matches!(
+            coordinates.tuple::<u32, 3>(coordinates.text, ','),
+            Err(AocError::Parse {
+                line: 3,
+                column: 3,
+                ..
+            })
+        ));
+
+        let machine = line("[.#] (3 {3}");
+        assert!(matches!(
+            machine.bracketed(&machine.text[4..7], '(', ')'),
+            Err(AocError::Parse { column: 5, .. })
+        ));
+
+        let pair = line("1,2,3");
+        assert!(matches!(
+            pair.tuple::<u32, 2>(pair.text, ','),
+            Err(AocError::Parse { column: 1, .. })
+        ));
+    }
+
+    #[test]
+    fn splits_sections() {
+        let sections = sections("3-5\n10-14\n\n1\n5\n");
+
+        assert_eq!(sections.len(), 2);
+        assert_eq!(
+            sections[1][0],
+            Line {
+                index: 3,
+                text: "1"
+            }
+        );
+    }
+}
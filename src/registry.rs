@@ -0,0 +1,67 @@
+use std::ops::RangeInclusive;
+
+use std::time::Duration;
+
+use crate::answers::AnswerStore;
+use crate::cli::Options;
+use crate::error::Result;
+use crate::runner::{self, DayReport, Stage};
+use crate::solution::{Part, Solution};
+use crate::{y2024, y2025};
+
+/// A registered solution with its puzzle date and type-erased entry points.
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    pub run: fn(&Options, &AnswerStore) -> DayReport,
+    /// Total time of running a stage on the given input a number of times, for benchmarks.
+    pub measure: fn(&str, Stage, u64) -> Result<Duration>,
+    /// Names of the alternative implementations of a part, each can be measured as a stage.
+    pub variants: fn(Part) -> Vec<&'static str>,
+}
+
+impl Day {
+    const fn of<S: Solution>() -> Day {
+        Day {
+            year: S::YEAR,
+            day: S::DAY,
+            run: runner::run::<S>,
+            measure: runner::measure::<S>,
+            variants: runner::variant_names::<S>,
+        }
+    }
+}
+
+// Keep sorted by (year, day).
+pub const DAYS: &[Day] = &[
+    Day::of::<y2024::day1::Day1>(),
+    Day::of::<y2024::day2::Day2>(),
+    Day::of::<y2024::day3::Day3>(),
+    Day::of::<y2025::day1::Day1>(),
+    Day::of::<y2025::day2::Day2>(),
+    Day::of::<y2025::day3::Day3>(),
+    Day::of::<y2025::day4::Day4>(),
+    Day::of::<y2025::day5::Day5>(),
+    Day::of::<y2025::day6::Day6>(),
+    Day::of::<y2025::day7::Day7>(),
+    Day::of::<y2025::day8::Day8>(),
+    Day::of::<y2025::day9::Day9>(),
+    Day::of::<y2025::day10::Day10>(),
+    Day::of::<y2025::day11::Day11>(),
+];
+
+pub fn latest() -> Option<&'static Day> {
+    DAYS.iter().max_by_key(|day| (day.year, day.day))
+}
+
+/// Registered days of `year` (the latest year when `None`) within `days`.
+pub fn select(year: Option<u16>, days: Option<RangeInclusive<u8>>) -> Vec<&'static Day> {
+    let Some(year) = year.or_else(|| latest().map(|day| day.year)) else {
+        return Vec::new();
+    };
+
+    DAYS.iter()
+        .filter(|day| day.year == year)
+        .filter(|day| days.as_ref().is_none_or(|days| days.contains(&day.day)))
+        .collect()
+}
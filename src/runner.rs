@@ -0,0 +1,590 @@
+use std::fmt;
+use std::hint::black_box;
+use std::io::{self, Read};
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use crate::answers::{AnswerKey, AnswerStore, Verdict};
+use crate::cli::{InputSource, Options};
+use crate::error::{AocError, Result};
+use crate::grid::Grid;
+use crate::inputs;
+use crate::solution::{Answer, Part, Solution};
+use crate::visual;
+
+use serde::Serialize;
+
+pub struct PartReport {
+    pub part: Part,
+    pub answer: Result<Answer>,
+    pub verdict: Verdict,
+    pub duration: Duration,
+    /// Alternative implementations of the part, only run with `--variants`.
+    pub variants: Vec<VariantReport>,
+}
+
+pub struct VariantReport {
+    pub name: &'static str,
+    pub answer: Result<Answer>,
+    pub duration: Duration,
+}
+
+impl VariantReport {
+    /// Whether the variant found the same answer as the main implementation of `part`.
+    pub fn agrees_with(&self, part: &PartReport) -> bool {
+        matches!((&self.answer, &part.answer), (Ok(a), Ok(b)) if a == b)
+    }
+}
+
+pub struct DayReport {
+    pub year: u16,
+    pub day: u8,
+    pub input: String,
+    pub input_path: String,
+    pub parse_duration: Duration,
+    /// Set when the input could not be loaded or parsed, no parts ran in that case.
+    pub error: Option<AocError>,
+    pub parts: Vec<PartReport>,
+    /// What the day drew with `--visualize`, and where the animation of it was written.
+    pub frames: Vec<Grid<char>>,
+    pub animation: Option<Result<PathBuf>>,
+}
+
+impl DayReport {
+    pub fn key(&self, part: Part) -> AnswerKey {
+        AnswerKey {
+            year: self.year,
+            day: self.day,
+            part,
+            input: self.input.clone(),
+        }
+    }
+
+    pub fn total_duration(&self) -> Duration {
+        self.parse_duration
+            + self
+                .parts
+                .iter()
+                .map(|part| part.duration)
+                .sum::<Duration>()
+    }
+
+    /// Whether the day failed to run or produced a wrong answer.
+    pub fn has_failures(&self) -> bool {
+        self.error.is_some()
+            || matches!(self.animation, Some(Err(_)))
+            || self.parts.iter().any(|part| {
+                part.answer.is_err()
+                    || matches!(part.verdict, Verdict::Wrong { .. })
+                    || part.variants.iter().any(|v| !v.agrees_with(part))
+            })
+    }
+}
+
+/// What a benchmark measures: parsing, or one part or a named variant of it on an already
+/// parsed input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stage {
+    Parse,
+    Part(Part),
+    Variant(Part, &'static str),
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Stage::Parse => write!(f, "parse"),
+            Stage::Part(part) => write!(f, "part{}", part),
+            Stage::Variant(part, name) => write!(f, "part{}/{}", part, name),
+        }
+    }
+}
+
+/// Runs `stage` `iterations` times on `contents` and returns the total time, not counting the
+/// parse a part needs first.
+pub fn measure<S: Solution>(contents: &str, stage: Stage, iterations: u64) -> Result<Duration> {
+    let part = match stage {
+        Stage::Parse => {
+            let start = Instant::now();
+            for _ in 0..iterations {
+                black_box(S::parse(black_box(contents))?);
+            }
+            return Ok(start.elapsed());
+        }
+        Stage::Part(part) => part,
+        Stage::Variant(part, name) => {
+            let Some((_, solve)) = S::variants(part).into_iter().find(|(n, _)| *n == name) else {
+                return Err(AocError::solver(format!(
+                    "No variant {} of part {}",
+                    name, part
+                )));
+            };
+
+            let input = S::parse(contents)?;
+            let start = Instant::now();
+            for _ in 0..iterations {
+                black_box(solve(black_box(&input))?);
+            }
+            return Ok(start.elapsed());
+        }
+    };
+
+    let input = S::parse(contents)?;
+    let start = Instant::now();
+    for _ in 0..iterations {
+        match part {
+            Part::One => drop(black_box(S::part_one(black_box(&input))?)),
+            Part::Two => drop(black_box(S::part_two(black_box(&input))?)),
+        }
+    }
+    Ok(start.elapsed())
+}
+
+/// Names of the variants registered for `part`.
+pub fn variant_names<S: Solution>(part: Part) -> Vec<&'static str> {
+    S::variants(part)
+        .into_iter()
+        .map(|(name, _)| name)
+        .collect()
+}
+
+/// The name an input is reported and its known answers are stored under, e.g. `day3.input`.
+pub fn input_name(options: &Options, day: u8) -> String {
+    match &options.input {
+        InputSource::Default => inputs::file_name(day, options.example.as_deref()),
+        InputSource::File(path) => path.file_name().map_or_else(
+            || path.display().to_string(),
+            |name| name.to_string_lossy().into_owned(),
+        ),
+        InputSource::Stdin => "stdin".to_string(),
+    }
+}
+
+/// Name, path and contents of the input a day reads.
+fn load_input<S: Solution>(options: &Options) -> (String, String, Result<String>) {
+    let input_name = input_name(options, S::DAY);
+
+    match &options.input {
+        InputSource::Default => match inputs::resolve(S::YEAR, &input_name) {
+            Ok(path) => (
+                input_name,
+                path.display().to_string(),
+                inputs::read_file(&path),
+            ),
+            Err(e) => (input_name.clone(), input_name, Err(e)),
+        },
+        InputSource::File(path) => (
+            input_name,
+            path.display().to_string(),
+            inputs::read_file(path),
+        ),
+        InputSource::Stdin => {
+            let mut contents = String::new();
+            let contents = io::stdin()
+                .read_to_string(&mut contents)
+                .map(|_| contents)
+                .map_err(|source| AocError::MissingInput {
+                    path: PathBuf::from("-"),
+                    source,
+                });
+            (input_name, "-".to_string(), contents)
+        }
+    }
+}
+
+pub fn run<S: Solution>(options: &Options, answers: &AnswerStore) -> DayReport {
+    let (input_name, input_path, contents) = load_input::<S>(options);
+
+    let mut report = DayReport {
+        year: S::YEAR,
+        day: S::DAY,
+        input: input_name,
+        input_path,
+        parse_duration: Duration::ZERO,
+        error: None,
+        parts: Vec::new(),
+        frames: Vec::new(),
+        animation: None,
+    };
+
+    let contents = match contents {
+        Ok(contents) => contents,
+        Err(e) => {
+            report.error = Some(e);
+            return report;
+        }
+    };
+
+    let start = Instant::now();
+    let parsed = S::parse(&contents);
+    report.parse_duration = start.elapsed();
+
+    let input = match parsed {
+        Ok(input) => input,
+        Err(e) => {
+            report.error = Some(e);
+            return report;
+        }
+    };
+
+    let mut add_part = |part: Part, solve: &dyn Fn() -> Result<Answer>| {
+        let start = Instant::now();
+        let answer = solve();
+        let duration = start.elapsed();
+
+        let verdict = match &answer {
+            Ok(answer) => answers.check(&report.key(part), answer),
+            Err(_) => Verdict::Unknown,
+        };
+        report.parts.push(PartReport {
+            part,
+            answer,
+            verdict,
+            duration,
+            variants: Vec::new(),
+        });
+    };
+
+    if options.part != Some(Part::Two) {
+        add_part(Part::One, &|| S::part_one(&input).map(Into::into));
+    }
+    if options.part != Some(Part::One) {
+        add_part(Part::Two, &|| S::part_two(&input).map(Into::into));
+    }
+
+    if options.variants {
+        for part in &mut report.parts {
+            for (name, solve) in S::variants(part.part) {
+                let start = Instant::now();
+                let answer = solve(&input);
+                let duration = start.elapsed();
+
+                part.variants.push(VariantReport {
+                    name,
+                    answer,
+                    duration,
+                });
+            }
+        }
+    }
+
+    if let Some(dir) = &options.visualize {
+        report.frames = S::frames(&input);
+        if !report.frames.is_empty() {
+            report.animation = Some(visual::write_animation(
+                dir,
+                S::YEAR,
+                S::DAY,
+                &report.frames,
+            ));
+        }
+    }
+
+    report
+}
+
+pub fn print_day(report: &DayReport, is_test_mode: bool) {
+    println!(
+        "📆 Day {}{}:",
+        report.day,
+        if is_test_mode { " (DEBUG)" } else { "" }
+    );
+    println!("------------");
+
+    if let Some(error) = &report.error {
+        println!("💥 {}", error);
+        println!();
+        return;
+    }
+    println!("Parsing - {}", format_duration(report.parse_duration));
+
+    for part in &report.parts {
+        let outcome = match (&part.answer, &part.verdict) {
+            (Err(e), _) => format!("💥 {}", e),
+            (Ok(answer), Verdict::Correct) => format!("{} ✅", answer),
+            (Ok(answer), Verdict::Wrong { expected }) => {
+                format!("{} ❌ (expected {})", answer, expected)
+            }
+            (Ok(answer), Verdict::Unknown) => format!("{} ❔", answer),
+        };
+        println!(
+            "Task {} - {} ({})",
+            part.part,
+            outcome,
+            format_duration(part.duration)
+        );
+
+        for variant in &part.variants {
+            let outcome = match &variant.answer {
+                Err(e) => format!("💥 {}", e),
+                Ok(answer) if variant.agrees_with(part) => format!("{} ✅", answer),
+                Ok(answer) => format!("{} ❌ (differs from task {})", answer, part.part),
+            };
+            println!(
+                "  ↳ {} - {} ({}, {})",
+                variant.name,
+                outcome,
+                format_duration(variant.duration),
+                relative_speed(variant.duration, part.duration)
+            );
+        }
+    }
+
+    if !report.frames.is_empty() {
+        println!();
+        print!("{}", visual::render_text(&report.frames));
+    }
+    match &report.animation {
+        Some(Ok(path)) => println!(
+            "🎞️  Wrote {} frames to {}",
+            report.frames.len(),
+            path.display()
+        ),
+        Some(Err(e)) => println!("💥 {}", e),
+        None => {}
+    }
+
+    println!();
+}
+
+/// How a variant's time compares to the main implementation, e.g. `2.50x slower`.
+fn relative_speed(variant: Duration, main: Duration) -> String {
+    let ratio = variant.as_secs_f64() / main.as_secs_f64().max(f64::MIN_POSITIVE);
+
+    if ratio >= 1.0 {
+        format!("{:.2}x slower", ratio)
+    } else {
+        format!("{:.2}x faster", 1.0 / ratio)
+    }
+}
+
+/// Prints the pass/fail tally and returns whether every day ran without a failure.
+pub fn print_summary(reports: &[DayReport]) -> bool {
+    let parts: Vec<&PartReport> = reports.iter().flat_map(|report| &report.parts).collect();
+
+    let count = |f: fn(&PartReport) -> bool| parts.iter().filter(|p| f(p)).count();
+    let correct = count(|p| p.answer.is_ok() && p.verdict == Verdict::Correct);
+    let wrong = count(|p| matches!(p.verdict, Verdict::Wrong { .. }));
+    let unknown = count(|p| p.answer.is_ok() && p.verdict == Verdict::Unknown);
+    let failed = count(|p| p.answer.is_err())
+        + reports
+            .iter()
+            .filter(|report| report.error.is_some())
+            .count()
+        + reports
+            .iter()
+            .filter(|report| matches!(report.animation, Some(Err(_))))
+            .count();
+
+    println!(
+        "✅ {} correct, ❌ {} wrong, ❔ {} unknown, 💥 {} failed",
+        correct, wrong, unknown, failed
+    );
+
+    for report in reports {
+        if let Some(error) = &report.error {
+            println!(
+                "💥 {} day {} ({}): {}",
+                report.year, report.day, report.input, error
+            );
+        }
+        if let Some(Err(e)) = &report.animation {
+            println!(
+                "💥 {} day {} ({}): {}",
+                report.year, report.day, report.input, e
+            );
+        }
+
+        for part in &report.parts {
+            match (&part.answer, &part.verdict) {
+                (Err(e), _) => println!(
+                    "💥 {} day {} part {} ({}): {}",
+                    report.year, report.day, part.part, report.input, e
+                ),
+                (Ok(answer), Verdict::Wrong { expected }) => println!(
+                    "❌ {} day {} part {} ({}): got {}, expected {}",
+                    report.year, report.day, part.part, report.input, answer, expected
+                ),
+                _ => {}
+            }
+
+            for variant in part.variants.iter().filter(|v| !v.agrees_with(part)) {
+                let answer = match &variant.answer {
+                    Ok(answer) => answer.to_string(),
+                    Err(e) => e.to_string(),
+                };
+                println!(
+                    "🔀 {} day {} part {} ({}): variant {} disagrees with {}",
+                    report.year, report.day, part.part, report.input, variant.name, answer
+                );
+            }
+        }
+    }
+
+    !reports.iter().any(DayReport::has_failures)
+}
+
+#[derive(Serialize)]
+struct JsonRecord<'a> {
+    year: u16,
+    day: u8,
+    part: Option<u8>,
+    answer: Option<&'a Answer>,
+    status: &'static str,
+    expected: Option<&'a str>,
+    error: Option<String>,
+    input: &'a str,
+    parse_ns: u128,
+    duration_ns: u128,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    variants: Vec<JsonVariant<'a>>,
+}
+
+#[derive(Serialize)]
+struct JsonVariant<'a> {
+    name: &'a str,
+    answer: Option<&'a Answer>,
+    agrees: bool,
+    error: Option<String>,
+    duration_ns: u128,
+}
+
+/// Prints one JSON object per line for each part of the day, or a single
+/// `"error"` record without a part when the day failed before solving.
+pub fn print_json(report: &DayReport) {
+    let print_record = |record: JsonRecord| {
+        println!(
+            "{}",
+            serde_json::to_string(&record).expect("Runner results are always serialisable")
+        );
+    };
+
+    if let Some(error) = &report.error {
+        print_record(JsonRecord {
+            year: report.year,
+            day: report.day,
+            part: None,
+            answer: None,
+            status: "error",
+            expected: None,
+            error: Some(error.to_string()),
+            input: &report.input_path,
+            parse_ns: report.parse_duration.as_nanos(),
+            duration_ns: 0,
+            variants: Vec::new(),
+        });
+        return;
+    }
+
+    for part in &report.parts {
+        let (status, expected) = match (&part.answer, &part.verdict) {
+            (Err(_), _) => ("error", None),
+            (Ok(_), Verdict::Correct) => ("correct", None),
+            (Ok(_), Verdict::Wrong { expected }) => ("wrong", Some(expected.as_str())),
+            (Ok(_), Verdict::Unknown) => ("unknown", None),
+        };
+
+        print_record(JsonRecord {
+            year: report.year,
+            day: report.day,
+            part: Some(match part.part {
+                Part::One => 1,
+                Part::Two => 2,
+            }),
+            answer: part.answer.as_ref().ok(),
+            status,
+            expected,
+            error: part.answer.as_ref().err().map(ToString::to_string),
+            input: &report.input_path,
+            parse_ns: report.parse_duration.as_nanos(),
+            duration_ns: part.duration.as_nanos(),
+            variants: part
+                .variants
+                .iter()
+                .map(|variant| JsonVariant {
+                    name: variant.name,
+                    answer: variant.answer.as_ref().ok(),
+                    agrees: variant.agrees_with(part),
+                    error: variant.answer.as_ref().err().map(ToString::to_string),
+                    duration_ns: variant.duration.as_nanos(),
+                })
+                .collect(),
+        });
+    }
+}
+
+/// Prints every day's timings, the total per year and flags the slowest days.
+pub fn print_timings(reports: &[DayReport]) {
+    const SLOWEST_COUNT: usize = 3;
+
+    let mut by_duration: Vec<&DayReport> = reports.iter().collect();
+    by_duration.sort_by_key(|report| std::cmp::Reverse(report.total_duration()));
+    let slowest: Vec<(u16, u8)> = by_duration
+        .iter()
+        .take(SLOWEST_COUNT)
+        .map(|report| (report.year, report.day))
+        .collect();
+
+    let part_duration = |report: &DayReport, part: Part| {
+        report
+            .parts
+            .iter()
+            .find(|p| p.part == part)
+            .map_or("-".to_string(), |p| format_duration(p.duration))
+    };
+
+    println!("⏱️  Timings");
+    println!(
+        "{:>4}  {:>3}  {:>10}  {:>10}  {:>10}  {:>10}",
+        "Year", "Day", "Parse", "Part 1", "Part 2", "Total"
+    );
+
+    let mut year_total = Duration::ZERO;
+    for (i, report) in reports.iter().enumerate() {
+        println!(
+            "{:>4}  {:>3}  {:>10}  {:>10}  {:>10}  {:>10}{}",
+            report.year,
+            report.day,
+            format_duration(report.parse_duration),
+            part_duration(report, Part::One),
+            part_duration(report, Part::Two),
+            format_duration(report.total_duration()),
+            if reports.len() > SLOWEST_COUNT && slowest.contains(&(report.year, report.day)) {
+                "  🐢"
+            } else {
+                ""
+            }
+        );
+
+        year_total += report.total_duration();
+        if reports
+            .get(i + 1)
+            .is_none_or(|next| next.year != report.year)
+        {
+            println!(
+                "{:>4}  {:>3}  {:>10}  {:>10}  {:>10}  {:>10}",
+                report.year,
+                "Σ",
+                "",
+                "",
+                "",
+                format_duration(year_total)
+            );
+            year_total = Duration::ZERO;
+        }
+    }
+
+    println!();
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    let micros = duration.as_secs_f64() * 1_000_000.0;
+
+    if micros < 1_000.0 {
+        format!("{:.1}µs", micros)
+    } else if micros < 1_000_000.0 {
+        format!("{:.2}ms", micros / 1_000.0)
+    } else {
+        format!("{:.2}s", micros / 1_000_000.0)
+    }
+}
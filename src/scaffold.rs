@@ -0,0 +1,258 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::error::{AocError, Result};
+use crate::inputs;
+
+const DAY_TEMPLATE: &str = r#"use crate::error::Result;
+use crate::solution::Solution;
+
+pub struct Day{DAY};
+
+impl Solution for Day{DAY} {
+    const YEAR: u16 = {YEAR};
+    const DAY: u8 = {DAY};
+
+    type Input<'a> = Vec<&'a str>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(input.lines().collect())
+    }
+
+    fn part_one(lines: &Self::Input<'_>) -> Result<u64> {
+        Ok(task_one(lines))
+    }
+
+    fn part_two(lines: &Self::Input<'_>) -> Result<u64> {
+        Ok(task_two(lines))
+    }
+}
+
+fn task_one(_lines: &[&str]) -> u64 {
+    0
+}
+
+fn task_two(_lines: &[&str]) -> u64 {
+    0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+";
+
+    #[test]
+    #[ignore = "example not filled in yet"]
+    fn part_one_example() {
+        assert_eq!(Day{DAY}::part_one(&Day{DAY}::parse(EXAMPLE).unwrap()).unwrap(), 0);
+    }
+
+    #[test]
+    #[ignore = "example not filled in yet"]
+    fn part_two_example() {
+        assert_eq!(Day{DAY}::part_two(&Day{DAY}::parse(EXAMPLE).unwrap()).unwrap(), 0);
+    }
+}
+"#;
+
+/// Creates the module of a new day from the template, registers it and creates its empty
+/// input files. Returns every created file.
+pub fn new_day(year: u16, day: u8) -> Result<Vec<PathBuf>> {
+    let src = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+    let year_dir = src.join(year.to_string());
+    let module = year_dir.join(format!("day{}.rs", day));
+
+    if module.exists() {
+        return Err(AocError::Io {
+            path: module,
+            source: io::ErrorKind::AlreadyExists.into(),
+        });
+    }
+
+    let mut created = Vec::new();
+    let year_mod = year_dir.join("mod.rs");
+    if !year_mod.exists() {
+        update(&src.join("lib.rs"), |lib| register_year(lib, year))?;
+        update(&src.join("registry.rs"), |registry| {
+            import_year(registry, year)
+        })?;
+        write(&year_mod, "")?;
+        created.push(year_mod.clone());
+    }
+
+    write(&module, &render(year, day))?;
+    created.push(module);
+    update(&year_mod, |mods| add_module(mods, day))?;
+    update(&src.join("registry.rs"), |registry| {
+        register_day(registry, year, day)
+    })?;
+
+    for example in [None, Some("test")] {
+        let input = inputs::cache_path(year, &inputs::file_name(day, example));
+        if !input.exists() {
+            write(&input, "")?;
+            created.push(input);
+        }
+    }
+
+    Ok(created)
+}
+
+fn render(year: u16, day: u8) -> String {
+    DAY_TEMPLATE
+        .replace("{YEAR}", &year.to_string())
+        .replace("{DAY}", &day.to_string())
+}
+
+/// Adds `pub mod day<day>;` where rustfmt would order it.
+fn add_module(mods: &str, day: u8) -> String {
+    let line = format!("pub mod day{};", day);
+    let mut lines: Vec<&str> = mods.lines().filter(|l| !l.trim().is_empty()).collect();
+    if !lines.contains(&line.as_str()) {
+        lines.push(&line);
+    }
+    // rustfmt orders by module name, so `day1` comes before `day10`
+    lines.sort_unstable_by_key(|l| l.trim_end_matches(';'));
+
+    lines.iter().map(|l| format!("{}\n", l)).collect()
+}
+
+/// Adds the day to `DAYS` in the registry, keeping it sorted by (year, day).
+fn register_day(registry: &str, year: u16, day: u8) -> String {
+    let entry = format!("    Day::of::<y{0}::day{1}::Day{1}>(),", year, day);
+
+    let mut lines: Vec<&str> = registry.lines().collect();
+    let start = lines
+        .iter()
+        .position(|l| l.starts_with("pub const DAYS"))
+        .expect("The registry defines DAYS");
+    let end = start
+        + lines[start..]
+            .iter()
+            .position(|l| l.trim() == "];")
+            .expect("DAYS is terminated");
+
+    let at = (start + 1..end)
+        .find(|&i| registered_date(lines[i]).is_some_and(|date| date > (year, day)))
+        .unwrap_or(end);
+    lines.insert(at, &entry);
+
+    lines.iter().map(|l| format!("{}\n", l)).collect()
+}
+
+/// Reads `(year, day)` back from a `Day::of::<y2025::day7::Day7>(),` line.
+fn registered_date(line: &str) -> Option<(u16, u8)> {
+    let path = line.trim().strip_prefix("Day::of::<y")?;
+    let (year, rest) = path.split_once("::day")?;
+    let (day, _) = rest.split_once("::")?;
+
+    Some((year.parse().ok()?, day.parse().ok()?))
+}
+
+/// Adds the year to the `use crate::{y2024, ...};` import of the registry.
+fn import_year(registry: &str, year: u16) -> String {
+    registry
+        .lines()
+        .map(|line| match line.strip_prefix("use crate::{y") {
+            Some(_) => {
+                let list = &line["use crate::{".len()..line.len() - "};".len()];
+                let mut years: Vec<String> = list.split(", ").map(str::to_string).collect();
+                years.push(format!("y{}", year));
+                years.sort_unstable();
+                years.dedup();
+                format!("use crate::{{{}}};\n", years.join(", "))
+            }
+            None => format!("{}\n", line),
+        })
+        .collect()
+}
+
+/// Declares the module of a new year in `lib.rs`, after the years before it.
+fn register_year(lib: &str, year: u16) -> String {
+    let declaration = format!("#[path = \"{0}/mod.rs\"]\npub mod y{0};", year);
+
+    let mut lines: Vec<String> = lib.lines().map(str::to_string).collect();
+    let last_earlier = lines
+        .iter()
+        .rposition(|l| {
+            l.strip_prefix("pub mod y")
+                .and_then(|l| l.strip_suffix(';'))
+                .and_then(|y| y.parse::<u16>().ok())
+                .is_some_and(|y| y < year)
+        })
+        .expect("lib.rs declares at least one year");
+    lines.insert(last_earlier + 1, declaration);
+
+    lines.iter().map(|l| format!("{}\n", l)).collect()
+}
+
+fn update(path: &Path, edit: impl FnOnce(&str) -> String) -> Result<()> {
+    let contents = inputs::read_file(path)?;
+    write(path, &edit(&contents))
+}
+
+fn write(path: &Path, contents: &str) -> Result<()> {
+    let io_error = |source| AocError::Io {
+        path: path.to_path_buf(),
+        source,
+    };
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(io_error)?;
+    }
+    fs::write(path, contents).map_err(io_error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn adds_module_in_rustfmt_order() {
+        assert_eq!(
+            add_module("pub mod day1;\npub mod day10;\npub mod day2;\n", 11),
+            "pub mod day1;\npub mod day10;\npub mod day11;\npub mod day2;\n"
+        );
+    }
+
+    #[test]
+    fn registers_day_sorted() {
+        let registry = "\
+pub const DAYS: &[Day] = &[
+    Day::of::<y2024::day3::Day3>(),
+    Day::of::<y2025::day1::Day1>(),
+    Day::of::<y2025::day11::Day11>(),
+];
+";
+        assert_eq!(
+            register_day(registry, 2025, 2),
+            "\
+pub const DAYS: &[Day] = &[
+    Day::of::<y2024::day3::Day3>(),
+    Day::of::<y2025::day1::Day1>(),
+    Day::of::<y2025::day2::Day2>(),
+    Day::of::<y2025::day11::Day11>(),
+];
+"
+        );
+        assert!(register_day(registry, 2025, 12)
+            .ends_with("    Day::of::<y2025::day12::Day12>(),\n];\n"));
+    }
+
+    #[test]
+    fn registers_new_year() {
+        let lib = "pub mod solution;\n#[path = \"2024/mod.rs\"]\npub mod y2024;\n";
+        assert_eq!(
+            register_year(lib, 2026),
+            "pub mod solution;\n#[path = \"2024/mod.rs\"]\npub mod y2024;\n#[path = \"2026/mod.rs\"]\npub mod y2026;\n"
+        );
+        assert_eq!(
+            import_year("use crate::{y2024, y2025};\n", 2026),
+            "use crate::{y2024, y2025, y2026};\n"
+        );
+    }
+}
@@ -0,0 +1,113 @@
+use std::fmt;
+use std::str::FromStr;
+
+use serde::Serialize;
+
+use crate::error::Result;
+use crate::grid::Grid;
+
+/// A single Advent of Code puzzle: how to parse its input and solve both parts.
+pub trait Solution {
+    const YEAR: u16;
+    const DAY: u8;
+
+    type Input<'a>;
+    type PartOne: Into<Answer>;
+    type PartTwo: Into<Answer>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>>;
+    fn part_one(input: &Self::Input<'_>) -> Result<Self::PartOne>;
+    fn part_two(input: &Self::Input<'_>) -> Result<Self::PartTwo>;
+
+    /// Alternative implementations of a part, which have to agree with `part_one`/`part_two`.
+    fn variants(_part: Part) -> Vec<Variant<Self>> {
+        Vec::new()
+    }
+
+    /// Snapshots of how the solution progresses, shown with `--visualize`.
+    fn frames(_input: &Self::Input<'_>) -> Vec<Grid<char>> {
+        Vec::new()
+    }
+}
+
+/// A named alternative implementation of a part of `S`.
+pub type Variant<S> = (
+    &'static str,
+    for<'a, 'b> fn(&'b <S as Solution>::Input<'a>) -> Result<Answer>,
+);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("Invalid part '{}', expected 1 or 2", s)),
+        }
+    }
+}
+
+/// A puzzle answer as handed back to the runner.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(untagged)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(number) => write!(f, "{}", number),
+            Answer::Text(text) => write!(f, "{}", text),
+        }
+    }
+}
+
+macro_rules! impl_answer_from_number {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for Answer {
+                fn from(number: $ty) -> Self {
+                    Answer::Number(number as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_answer_from_number!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, i128);
+
+impl From<u128> for Answer {
+    fn from(number: u128) -> Self {
+        i128::try_from(number).map_or_else(|_| Answer::Text(number.to_string()), Answer::Number)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.to_string())
+    }
+}
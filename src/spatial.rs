@@ -0,0 +1,205 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+/// How many neighbours a point looks up at first, doubled whenever they are used up.
+const INITIAL_NEIGHBOURS: usize = 4;
+
+/// A k-d tree over borrowed points, stored implicitly: the median of every range of `order`
+/// splits the rest of it along the axis of its depth.
+pub struct KdTree<'a, const N: usize> {
+    points: &'a [[i64; N]],
+    order: Vec<usize>,
+}
+
+/// Squared euclidean distance, computed in `u128` so it is exact for 32 bit coordinates.
+pub fn squared_distance<const N: usize>(a: &[i64; N], b: &[i64; N]) -> u128 {
+    a.iter()
+        .zip(b)
+        .map(|(a, b)| (a.abs_diff(*b) as u128).pow(2))
+        .sum()
+}
+
+impl<'a, const N: usize> KdTree<'a, N> {
+    pub fn new(points: &'a [[i64; N]]) -> KdTree<'a, N> {
+        let mut order: Vec<usize> = (0..points.len()).collect();
+        build(points, &mut order, 0);
+
+        KdTree { points, order }
+    }
+
+    /// Up to `k` indices of points closest to the point at `index` which `accept` allows,
+    /// closest first with ties ordered by index.
+    pub fn nearest(
+        &self,
+        index: usize,
+        k: usize,
+        accept: impl Fn(usize) -> bool,
+    ) -> Vec<(u128, usize)> {
+        let mut best = BinaryHeap::with_capacity(k + 1);
+        if k > 0 {
+            self.search(0, self.order.len(), 0, index, k, &accept, &mut best);
+        }
+        best.into_sorted_vec()
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn search(
+        &self,
+        lo: usize,
+        hi: usize,
+        depth: usize,
+        target: usize,
+        k: usize,
+        accept: &impl Fn(usize) -> bool,
+        best: &mut BinaryHeap<(u128, usize)>,
+    ) {
+        if lo >= hi {
+            return;
+        }
+
+        let mid = (lo + hi) / 2;
+        let index = self.order[mid];
+        let target_point = &self.points[target];
+
+        if index != target && accept(index) {
+            let candidate = (squared_distance(target_point, &self.points[index]), index);
+            if best.len() < k {
+                best.push(candidate);
+            } else if best.peek().is_some_and(|&worst| candidate < worst) {
+                best.pop();
+                best.push(candidate);
+            }
+        }
+
+        let axis = depth % N;
+        let offset = target_point[axis] - self.points[index][axis];
+        let (near, far) = if offset < 0 {
+            ((lo, mid), (mid + 1, hi))
+        } else {
+            ((mid + 1, hi), (lo, mid))
+        };
+
+        self.search(near.0, near.1, depth + 1, target, k, accept, best);
+
+        // Everything on the far side is at least as far away as the splitting plane
+        let plane = (offset.unsigned_abs() as u128).pow(2);
+        if best.len() < k || best.peek().is_some_and(|&(worst, _)| plane <= worst) {
+            self.search(far.0, far.1, depth + 1, target, k, accept, best);
+        }
+    }
+}
+
+fn build<const N: usize>(points: &[[i64; N]], order: &mut [usize], depth: usize) {
+    if order.len() <= 1 {
+        return;
+    }
+
+    let axis = depth % N;
+    let mid = order.len() / 2;
+    order.select_nth_unstable_by_key(mid, |&i| points[i][axis]);
+
+    let (left, right) = order.split_at_mut(mid);
+    build(points, left, depth + 1);
+    build(points, &mut right[1..], depth + 1);
+}
+
+/// Every pair `(squared distance, i, j)` with `i < j`, closest first with ties ordered by
+/// index, found lazily from each point's nearest neighbours.
+pub struct ClosestPairs<'a, const N: usize> {
+    tree: KdTree<'a, N>,
+    neighbours: Vec<Neighbours>,
+    queue: BinaryHeap<Reverse<(u128, usize, usize)>>,
+}
+
+/// The neighbours of a point with a higher index, as far as they were looked up.
+#[derive(Default)]
+struct Neighbours {
+    found: Vec<(u128, usize)>,
+    next: usize,
+    exhausted: bool,
+}
+
+pub fn closest_pairs<const N: usize>(points: &[[i64; N]]) -> ClosestPairs<'_, N> {
+    let mut pairs = ClosestPairs {
+        tree: KdTree::new(points),
+        neighbours: (0..points.len()).map(|_| Neighbours::default()).collect(),
+        queue: BinaryHeap::new(),
+    };
+    for i in 0..points.len() {
+        pairs.queue_next(i);
+    }
+
+    pairs
+}
+
+impl<const N: usize> ClosestPairs<'_, N> {
+    /// Queues the next closest pair of point `i`, looking up more neighbours when needed.
+    fn queue_next(&mut self, i: usize) {
+        let neighbours = &mut self.neighbours[i];
+
+        if neighbours.next == neighbours.found.len() && !neighbours.exhausted {
+            let k = (neighbours.found.len() * 2).max(INITIAL_NEIGHBOURS);
+            // The k nearest are ordered by (distance, index), so they extend the ones before
+            neighbours.found = self.tree.nearest(i, k, |j| j > i);
+            neighbours.exhausted = neighbours.found.len() < k;
+        }
+
+        if let Some(&(distance, j)) = neighbours.found.get(neighbours.next) {
+            neighbours.next += 1;
+            self.queue.push(Reverse((distance, i, j)));
+        }
+    }
+}
+
+impl<const N: usize> Iterator for ClosestPairs<'_, N> {
+    type Item = (u128, usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Reverse(pair) = self.queue.pop()?;
+        self.queue_next(pair.1);
+
+        Some(pair)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn random_points(count: usize) -> Vec<[i64; 3]> {
+        let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+        let mut next = || {
+            state = state
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1);
+            // Few distinct values, so plenty of ties and duplicate points
+            (state >> 33) as i64 % 50
+        };
+
+        (0..count).map(|_| [next(), next(), next()]).collect()
+    }
+
+    #[test]
+    fn yields_all_pairs_in_order() {
+        let points = random_points(200);
+
+        let mut expected = Vec::new();
+        for i in 0..points.len() {
+            for j in i + 1..points.len() {
+                expected.push((squared_distance(&points[i], &points[j]), i, j));
+            }
+        }
+        expected.sort_unstable();
+
+        assert_eq!(closest_pairs(&points).collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn finds_nearest_neighbours() {
+        let points = [[0, 0], [5, 5], [1, 0], [0, 2], [-1, -1]];
+        let tree = KdTree::new(&points);
+
+        assert_eq!(tree.nearest(0, 3, |_| true), vec![(1, 2), (2, 4), (4, 3)]);
+        assert_eq!(tree.nearest(0, 2, |j| j > 2), vec![(2, 4), (4, 3)]);
+    }
+}
@@ -0,0 +1,282 @@
+use std::time::{Duration, SystemTime};
+
+use crate::client::Client;
+use crate::error::{AocError, Result};
+use crate::history::{History, Outcome, Submission};
+use crate::solution::{Answer, Part};
+
+/// How long the server refuses submissions after a wrong answer, unless the page says otherwise.
+const WRONG_ANSWER_COOLDOWN: Duration = Duration::from_secs(60);
+
+/// The interesting part of the page the server answers a submission with.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Response {
+    Verdict(Outcome),
+    /// Rate limited, nothing was checked.
+    Wait(Duration),
+    /// The part was solved before, nothing was checked.
+    AlreadySolved,
+}
+
+/// Submits `answer` unless the history rules it out at `now`, and records the verdict and any
+/// rate limit that follows it in the history.
+pub fn submit(
+    client: &Client,
+    history: &mut History,
+    year: u16,
+    day: u8,
+    part: Part,
+    answer: &Answer,
+    now: SystemTime,
+) -> Result<Response> {
+    if let Some(reason) = history.refusal(year, day, part, answer, now) {
+        return Err(AocError::Refused(reason));
+    }
+
+    let page = client.submit(year, day, part, answer)?;
+    let response = parse_response(&page)?;
+
+    let wait = match response {
+        Response::Wait(wait) => Some(wait),
+        Response::Verdict(Outcome::Correct) | Response::AlreadySolved => None,
+        Response::Verdict(_) => Some(parse_cooldown(&page).unwrap_or(WRONG_ANSWER_COOLDOWN)),
+    };
+    if let Some(wait) = wait {
+        history.wait_until(now + wait);
+    }
+
+    if let Response::Verdict(outcome) = response {
+        history.record(Submission {
+            year,
+            day,
+            part,
+            answer: answer.to_string(),
+            outcome,
+        });
+    }
+
+    Ok(response)
+}
+
+pub fn parse_response(page: &str) -> Result<Response> {
+    if page.contains("That's the right answer") {
+        Ok(Response::Verdict(Outcome::Correct))
+    } else if page.contains("That's not the right answer") {
+        Ok(Response::Verdict(
+            if page.contains("your answer is too high") {
+                Outcome::TooHigh
+            } else if page.contains("your answer is too low") {
+                Outcome::TooLow
+            } else {
+                Outcome::Wrong
+            },
+        ))
+    } else if page.contains("You gave an answer too recently") {
+        Ok(Response::Wait(parse_wait(page).unwrap_or(Duration::ZERO)))
+    } else if page.contains("You don't seem to be solving the right level") {
+        Ok(Response::AlreadySolved)
+    } else {
+        Err(AocError::request("Unexpected response to the submission"))
+    }
+}
+
+/// Reads the `You have 1m 5s left to wait` part of a rate limited response.
+fn parse_wait(page: &str) -> Option<Duration> {
+    let start = page.find("You have ")? + "You have ".len();
+    let end = start + page[start..].find(" left to wait")?;
+
+    let mut seconds = 0;
+    for token in page[start..end].split_whitespace() {
+        let (value, unit) = token.split_at(token.find(|c: char| !c.is_ascii_digit())?);
+        seconds += value.parse::<u64>().ok()?
+            * match unit {
+                "h" => 3600,
+                "m" => 60,
+                "s" => 1,
+                _ => return None,
+            };
+    }
+
+    Some(Duration::from_secs(seconds))
+}
+
+/// Reads the `Please wait 5 minutes before trying again` cooldown of a wrong answer.
+fn parse_cooldown(page: &str) -> Option<Duration> {
+    let start = page.find("lease wait ")? + "lease wait ".len();
+    let end = start + page[start..].find(" before trying again")?;
+
+    let (value, unit) = page[start..end].split_once(' ')?;
+    let value = match value {
+        "one" => 1,
+        value => value.parse::<u64>().ok()?,
+    };
+    let seconds = match unit.trim_end_matches('s') {
+        "second" => 1,
+        "minute" => 60,
+        "hour" => 3600,
+        _ => return None,
+    };
+
+    Some(Duration::from_secs(value * seconds))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+    use crate::client::test_server;
+
+    const TOO_HIGH: &str = "<article><p>That's not the right answer; your answer is too high. \
+        Please wait one minute before trying again.</p></article>";
+    const CORRECT: &str = "<article><p>That's the right answer! You are one gold star closer \
+        to decorating the North Pole.</p></article>";
+    const WAIT: &str = "<article><p>You gave an answer too recently; you have to wait after \
+        submitting an answer before trying again.  You have 1m 5s left to wait.</p></article>";
+
+    fn empty_history() -> History {
+        let path = std::env::temp_dir().join(format!("aoc-history-{}", std::process::id()));
+        History::load(&path.join("history.txt")).unwrap()
+    }
+
+    #[test]
+    fn parses_responses() {
+        assert_eq!(
+            parse_response(TOO_HIGH).unwrap(),
+            Response::Verdict(Outcome::TooHigh)
+        );
+        assert_eq!(
+            parse_response(CORRECT).unwrap(),
+            Response::Verdict(Outcome::Correct)
+        );
+        assert_eq!(
+            parse_response(WAIT).unwrap(),
+            Response::Wait(Duration::from_secs(65))
+        );
+        assert!(parse_response("<html></html>").is_err());
+
+        assert_eq!(parse_cooldown(TOO_HIGH), Some(Duration::from_secs(60)));
+        assert_eq!(
+            parse_cooldown("please wait 5 minutes before trying again."),
+            Some(Duration::from_secs(300))
+        );
+    }
+
+    #[test]
+    fn records_verdicts_and_refuses_ruled_out_answers() {
+        let (base_url, server) = test_server::serve(vec![(200, TOO_HIGH), (200, CORRECT)]);
+        let client = Client::new(&base_url, Some("secret"));
+        let mut history = empty_history();
+        let now = SystemTime::now();
+        // Past the minute a wrong answer has to wait
+        let later = now + Duration::from_secs(120);
+
+        let response = submit(&client, &mut history, 2025, 5, Part::One, &500.into(), now);
+        assert_eq!(response.unwrap(), Response::Verdict(Outcome::TooHigh));
+
+        for ruled_out in [500, 501] {
+            assert!(matches!(
+                submit(
+                    &client,
+                    &mut history,
+                    2025,
+                    5,
+                    Part::One,
+                    &ruled_out.into(),
+                    later
+                ),
+                Err(AocError::Refused(_))
+            ));
+        }
+
+        let response = submit(
+            &client,
+            &mut history,
+            2025,
+            5,
+            Part::One,
+            &499.into(),
+            later,
+        );
+        assert_eq!(response.unwrap(), Response::Verdict(Outcome::Correct));
+
+        let requests = server.join().unwrap();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].path, "/2025/day/5/answer");
+        assert_eq!(requests[0].body, "level=1&answer=500");
+        assert_eq!(requests[1].body, "level=1&answer=499");
+    }
+
+    #[test]
+    fn rate_limit_is_not_recorded() {
+        let (base_url, server) = test_server::serve(vec![(200, WAIT), (200, CORRECT)]);
+        let client = Client::new(&base_url, Some("secret"));
+        let mut history = empty_history();
+        let now = SystemTime::now();
+
+        let answer = Answer::from("abc");
+        let response = submit(&client, &mut history, 2025, 5, Part::Two, &answer, now);
+        assert_eq!(response.unwrap(), Response::Wait(Duration::from_secs(65)));
+
+        let later = now + Duration::from_secs(65);
+        let response = submit(&client, &mut history, 2025, 5, Part::Two, &answer, later);
+        assert_eq!(response.unwrap(), Response::Verdict(Outcome::Correct));
+
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn waits_out_rate_limits() {
+        let (base_url, server) = test_server::serve(vec![(200, WAIT), (200, TOO_HIGH)]);
+        let client = Client::new(&base_url, Some("secret"));
+        let dir = std::env::temp_dir().join(format!("aoc-history-wait-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let mut history = History::load(&dir.join("history.txt")).unwrap();
+        let now = SystemTime::now();
+
+        let response = submit(&client, &mut history, 2025, 5, Part::One, &500.into(), now);
+        assert_eq!(response.unwrap(), Response::Wait(Duration::from_secs(65)));
+        let early = now + Duration::from_secs(64);
+        assert!(matches!(
+            submit(
+                &client,
+                &mut history,
+                2025,
+                5,
+                Part::One,
+                &500.into(),
+                early
+            ),
+            Err(AocError::Refused(_))
+        ));
+
+        // A wrong answer is followed by a minute of waiting as well
+        let later = now + Duration::from_secs(65);
+        let response = submit(
+            &client,
+            &mut history,
+            2025,
+            5,
+            Part::One,
+            &500.into(),
+            later,
+        );
+        assert_eq!(response.unwrap(), Response::Verdict(Outcome::TooHigh));
+
+        // The wait outlives the process
+        history.save().unwrap();
+        let history = History::load(&dir.join("history.txt")).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        let answer = Answer::Number(400);
+        let early = later + Duration::from_secs(59);
+        assert!(history
+            .refusal(2025, 5, Part::One, &answer, early)
+            .is_some());
+        let later = later + Duration::from_secs(61);
+        assert_eq!(history.refusal(2025, 5, Part::One, &answer, later), None);
+
+        assert_eq!(server.join().unwrap().len(), 2);
+    }
+}
@@ -0,0 +1,98 @@
+/// Disjoint sets over the elements `0..n`, with path compression and union by size.
+#[derive(Debug, Clone)]
+pub struct UnionFind {
+    parent: Vec<usize>,
+    /// Size of the component, only kept up to date for roots.
+    size: Vec<usize>,
+    components: usize,
+}
+
+impl UnionFind {
+    /// `n` elements, each in a component of its own.
+    pub fn new(n: usize) -> UnionFind {
+        UnionFind {
+            parent: (0..n).collect(),
+            size: vec![1; n],
+            components: n,
+        }
+    }
+
+    /// The representative of the component containing `element`.
+    pub fn find(&mut self, element: usize) -> usize {
+        let mut root = element;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+
+        // Point everything on the way directly at the root
+        let mut current = element;
+        while self.parent[current] != root {
+            current = std::mem::replace(&mut self.parent[current], root);
+        }
+
+        root
+    }
+
+    /// Joins the components of `a` and `b`, returns false if they already were one.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+
+        if self.size[a] < self.size[b] {
+            std::mem::swap(&mut a, &mut b);
+        }
+        self.parent[b] = a;
+        self.size[a] += self.size[b];
+        self.components -= 1;
+
+        true
+    }
+
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// Number of elements in the component containing `element`.
+    pub fn component_size(&mut self, element: usize) -> usize {
+        let root = self.find(element);
+        self.size[root]
+    }
+
+    pub fn component_count(&self) -> usize {
+        self.components
+    }
+
+    /// The size of every component, in no particular order.
+    pub fn component_sizes(&self) -> Vec<usize> {
+        (0..self.parent.len())
+            .filter(|&element| self.parent[element] == element)
+            .map(|root| self.size[root])
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn joins_components() {
+        let mut sets = UnionFind::new(6);
+
+        assert!(sets.union(0, 1));
+        assert!(sets.union(2, 3));
+        assert!(sets.union(1, 3));
+        assert!(!sets.union(0, 2));
+
+        assert!(sets.connected(0, 3));
+        assert!(!sets.connected(0, 4));
+        assert_eq!(sets.component_size(2), 4);
+        assert_eq!(sets.component_count(), 3);
+
+        let mut sizes = sets.component_sizes();
+        sizes.sort_unstable();
+        assert_eq!(sizes, vec![1, 1, 4]);
+    }
+}
@@ -0,0 +1,117 @@
+use std::fs::{self, File};
+use std::io::BufWriter;
+use std::path::{Path, PathBuf};
+
+use gif::{Encoder, Frame, Repeat};
+
+use crate::error::{AocError, Result};
+use crate::grid::Grid;
+
+/// Width and height of a cell in the animation, in pixels.
+const CELL_PIXELS: usize = 4;
+/// Time a frame is shown, in hundredths of a second.
+const FRAME_DELAY: u16 = 15;
+
+/// The colours of the animation as `(cell, [r, g, b])`, any other cell is drawn as the last one.
+const PALETTE: [(char, [u8; 3]); 8] = [
+    ('.', [24, 24, 32]),
+    ('@', [196, 164, 112]),
+    ('x', [220, 60, 60]),
+    ('S', [80, 200, 120]),
+    ('^', [150, 150, 170]),
+    ('|', [250, 220, 80]),
+    ('#', [240, 240, 240]),
+    (' ', [0, 0, 0]),
+];
+
+/// The frames as text, one grid after another with a header each.
+pub fn render_text(frames: &[Grid<char>]) -> String {
+    frames
+        .iter()
+        .enumerate()
+        .map(|(i, frame)| format!("Frame {}/{}\n{}\n", i + 1, frames.len(), frame))
+        .collect()
+}
+
+/// Writes the frames as a looping animated GIF to `<dir>/<year>-day<day>.gif`.
+pub fn write_animation(dir: &Path, year: u16, day: u8, frames: &[Grid<char>]) -> Result<PathBuf> {
+    let path = dir.join(format!("{}-day{}.gif", year, day));
+    let io_error = |source| AocError::Io {
+        path: path.clone(),
+        source,
+    };
+    let gif_error =
+        |e: gif::EncodingError| AocError::solver(format!("Writing the GIF failed: {}", e));
+
+    let Some(first) = frames.first() else {
+        return Err(AocError::solver("There are no frames to animate"));
+    };
+    let too_large = || AocError::solver("The frames are too large for a GIF");
+    let width = u16::try_from(first.width() * CELL_PIXELS).map_err(|_| too_large())?;
+    let height = u16::try_from(first.height() * CELL_PIXELS).map_err(|_| too_large())?;
+
+    fs::create_dir_all(dir).map_err(io_error)?;
+    let file = File::create(&path).map_err(io_error)?;
+
+    let palette: Vec<u8> = PALETTE.iter().flat_map(|(_, rgb)| *rgb).collect();
+    let mut encoder =
+        Encoder::new(BufWriter::new(file), width, height, &palette).map_err(gif_error)?;
+    encoder.set_repeat(Repeat::Infinite).map_err(gif_error)?;
+
+    for grid in frames {
+        let mut frame = Frame::from_indexed_pixels(width, height, pixels(grid), None);
+        frame.delay = FRAME_DELAY;
+        encoder.write_frame(&frame).map_err(gif_error)?;
+    }
+
+    Ok(path)
+}
+
+/// Palette indices of every pixel of the grid, with each cell scaled up to a square.
+fn pixels(grid: &Grid<char>) -> Vec<u8> {
+    let mut pixels = Vec::with_capacity(grid.width() * grid.height() * CELL_PIXELS.pow(2));
+
+    for row in grid.rows() {
+        let line: Vec<u8> = row
+            .iter()
+            .flat_map(|&cell| [colour(cell); CELL_PIXELS])
+            .collect();
+        for _ in 0..CELL_PIXELS {
+            pixels.extend_from_slice(&line);
+        }
+    }
+
+    pixels
+}
+
+fn colour(cell: char) -> u8 {
+    PALETTE
+        .iter()
+        .position(|&(c, _)| c == cell)
+        .unwrap_or(PALETTE.len() - 1) as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_frames() {
+        let frames = [Grid::parse("@@\n").unwrap(), Grid::parse("x@\n").unwrap()];
+
+        assert_eq!(render_text(&frames), "Frame 1/2\n@@\n\nFrame 2/2\nx@\n\n");
+        assert_eq!(pixels(&frames[1]).len(), 2 * CELL_PIXELS.pow(2));
+    }
+
+    #[test]
+    fn writes_animation() {
+        let dir = std::env::temp_dir().join(format!("aoc-visual-{}", std::process::id()));
+        let frames = [Grid::parse("@.\n.@\n").unwrap()];
+
+        let path = write_animation(&dir, 2025, 4, &frames).unwrap();
+
+        let contents = fs::read(path).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+        assert!(contents.starts_with(b"GIF89a"));
+    }
+}